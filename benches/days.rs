@@ -0,0 +1,76 @@
+use std::{
+    fs,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2021::solution::{Entry, input_path, registry};
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::WallTime,
+};
+use itertools::Itertools;
+
+// Anything slower than this only gets the minimum number of samples
+const SLOW: Duration = Duration::from_millis(20);
+
+fn read_input(year: u16, day: u8) -> Option<String> {
+    let input = fs::read_to_string(input_path(year, day)).ok()?;
+    let input = input.trim_end_matches('\n');
+
+    // Placeholder files are left empty
+    (!input.is_empty()).then(|| input.to_string())
+}
+
+fn name(entry: &Entry, phase: &str) -> String {
+    match entry.variant {
+        Some(variant) => format!("{phase}/{variant}"),
+        None => phase.to_string(),
+    }
+}
+
+fn bench_entry(group: &mut BenchmarkGroup<WallTime>, entry: &Entry, input: &str) {
+    let start = Instant::now();
+    let runner = match entry.generate(input) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("skipping {entry}: {e}");
+            return;
+        }
+    };
+    runner.run();
+
+    group.sample_size(if start.elapsed() > SLOW { 10 } else { 100 });
+
+    // Each variant is its own generator/part pair, so only benchmark the generator once per variant
+    if entry.part == 1 {
+        group.bench_function(name(entry, "generator"), |b| {
+            b.iter(|| entry.generate(black_box(input)))
+        });
+    }
+
+    group.bench_function(name(entry, &format!("part{}", entry.part)), |b| {
+        b.iter(|| runner.run())
+    });
+}
+
+fn bench_days(c: &mut Criterion) {
+    for ((year, day), entries) in &registry().iter().chunk_by(|e| (e.year, e.day)) {
+        let Some(input) = read_input(year, day) else {
+            let path = input_path(year, day);
+            eprintln!(
+                "skipping {year} day {day}: {} is missing or empty",
+                path.display()
+            );
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("{year}/day{day:02}"));
+        for entry in entries {
+            bench_entry(&mut group, entry, &input);
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+
+    #[cfg(feature = "header")]
+    header();
+}
+
+// Only generated on request, so normal builds don't need cbindgen. The header goes to `OUT_DIR`,
+// where `ffi::test_generated_header` compares it with the committed one, and only replaces
+// `include/aoc2021.h` when `AOC2021_UPDATE_HEADER` is set
+#[cfg(feature = "header")]
+fn header() {
+    let dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out = std::env::var("OUT_DIR").unwrap();
+    println!("cargo::rerun-if-changed=src/ffi.rs");
+    println!("cargo::rerun-if-changed=cbindgen.toml");
+    println!("cargo::rerun-if-env-changed=AOC2021_UPDATE_HEADER");
+
+    // Only ffi.rs is parsed, so public constants elsewhere in the crate stay out of the C ABI
+    let config = cbindgen::Config::from_file(format!("{dir}/cbindgen.toml"))
+        .expect("unable to read cbindgen.toml");
+    let bindings = cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(format!("{dir}/src/ffi.rs"))
+        .generate()
+        .expect("unable to generate the C header");
+
+    bindings.write_to_file(format!("{out}/aoc2021.h"));
+    if std::env::var_os("AOC2021_UPDATE_HEADER").is_some() {
+        bindings.write_to_file(format!("{dir}/include/aoc2021.h"));
+    }
+}
@@ -0,0 +1,56 @@
+#ifndef AOC2021_H
+#define AOC2021_H
+
+/* Generated from src/ffi.rs by `AOC2021_UPDATE_HEADER=1 cargo build --features header`, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The answer was written to `out_buf`.
+#define AOC2021_OK 0
+
+// `input` or `out_buf` was null.
+#define AOC2021_ERR_NULL -1
+
+// The input isn't valid UTF-8.
+#define AOC2021_ERR_UTF8 -2
+
+// There is no solution for the day and part.
+#define AOC2021_ERR_UNKNOWN -3
+
+// The input failed to parse, the reason is written to `out_buf`.
+#define AOC2021_ERR_PARSE -4
+
+// `out_buf` is too small for the answer and its terminating NUL.
+#define AOC2021_ERR_BUFFER -5
+
+// The solver panicked.
+#define AOC2021_ERR_PANIC -6
+
+// The input parsed, but has no answer.
+#define AOC2021_ERR_NO_ANSWER -7
+
+// Solves `part` of `day` of 2021 for the `len` bytes of UTF-8 at `input`, writing the answer to
+// `out_buf` as a NUL-terminated string of at most `out_len` bytes.
+//
+// Returns [`AOC2021_OK`] or one of the `AOC2021_ERR_*` codes, on [`AOC2021_ERR_PARSE`] `out_buf`
+// holds the reason instead, truncated to fit.
+//
+// # Safety
+//
+// `input` must point to `len` readable bytes, and `out_buf` to `out_len` writable bytes. Neither
+// may be null, even when the length is 0.
+int aoc2021_solve(uint8_t day,
+                  uint8_t part,
+                  const uint8_t *input,
+                  size_t len,
+                  char *out_buf,
+                  size_t out_len);
+
+// A static, NUL-terminated description of a code returned by [`aoc2021_solve`].
+const char *aoc2021_strerror(int code);
+
+#endif  /* AOC2021_H */
@@ -0,0 +1,117 @@
+//! A global allocator that keeps track of the peak number of live allocations, for the run report.
+//!
+//! Binaries opt in with `#[global_allocator] static ALLOC: CountingAlloc = CountingAlloc;`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering::Relaxed},
+};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the [`System`] allocator, counting every allocation.
+pub struct CountingAlloc;
+
+fn grow(bytes: usize, count: usize) {
+    INSTALLED.store(true, Relaxed);
+
+    let live = LIVE.fetch_add(count, Relaxed) + count;
+    PEAK.fetch_max(live, Relaxed);
+    let live_bytes = LIVE_BYTES.fetch_add(bytes, Relaxed) + bytes;
+    PEAK_BYTES.fetch_max(live_bytes, Relaxed);
+}
+
+fn shrink(bytes: usize, count: usize) {
+    LIVE.fetch_sub(count, Relaxed);
+    LIVE_BYTES.fetch_sub(bytes, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            grow(layout.size(), 1);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            grow(layout.size(), 1);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        shrink(layout.size(), 1);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            // Count the new block before freeing the old one, the peak includes both for a moment
+            grow(new_size, 0);
+            shrink(layout.size(), 0);
+        }
+
+        new
+    }
+}
+
+/// Allocations made since a call to [`Peak::start`], at the point the most were live at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Peak {
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+impl Peak {
+    /// Starts a new measurement, returning what is live now to pass to [`Peak::since`].
+    ///
+    /// Other threads allocating at the same time are counted too.
+    pub fn start() -> Self {
+        let allocations = LIVE.load(Relaxed);
+        let bytes = LIVE_BYTES.load(Relaxed);
+        PEAK.store(allocations, Relaxed);
+        PEAK_BYTES.store(bytes, Relaxed);
+
+        Self { allocations, bytes }
+    }
+
+    /// The peak since `start`, or `None` if [`CountingAlloc`] isn't the global allocator.
+    pub fn since(start: Self) -> Option<Self> {
+        INSTALLED.load(Relaxed).then(|| Self {
+            allocations: PEAK.load(Relaxed).saturating_sub(start.allocations),
+            bytes: PEAK_BYTES.load(Relaxed).saturating_sub(start.bytes),
+        })
+    }
+}
+
+// The tests run with it installed, so they can check what it counts
+#[cfg(test)]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_peak() {
+        let start = Peak::start();
+        let held: Vec<Vec<u8>> = (0..10).map(|n| vec![0; 100 + n]).collect();
+        let peak = Peak::since(start).unwrap();
+
+        assert!(peak.allocations >= 11, "{peak:?}");
+        assert!(peak.bytes >= 10 * 100, "{peak:?}");
+        drop(held);
+    }
+}
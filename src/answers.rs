@@ -0,0 +1,341 @@
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use toml::{Table, Value};
+
+use crate::{
+    solution::{CheckError, cross_check, input_path},
+    y2021,
+};
+
+/// The answers expected for one puzzle input, a part is `None` when it isn't checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub year: u16,
+    pub day: u8,
+    pub input: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// A list of puzzle inputs and their answers, read from a TOML file like `answers.toml`.
+///
+/// `year` defaults to 2021 and `input` to [`input_path`] for the year and day.
+///
+/// ```toml
+/// [[answers]]
+/// year = 2021
+/// day = 1
+/// input = "input/2021/day1.txt"
+/// part1 = 1676
+/// part2 = 1706
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Manifest(pub Vec<Expected>);
+
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(PathBuf, io::Error),
+    Toml(toml::de::Error),
+    /// The entry at `index` is missing a field or has one of the wrong type.
+    Invalid {
+        index: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io(path, e) => write!(f, "unable to read {}: {e}", path.display()),
+            ManifestError::Toml(e) => write!(f, "{e}"),
+            ManifestError::Invalid { index, reason } => {
+                write!(f, "answers entry {}: {reason}", index + 1)
+            }
+        }
+    }
+}
+
+impl Error for ManifestError {}
+
+impl Manifest {
+    /// Reads the manifest at `path`, input paths are relative to the directory it is in.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ManifestError> {
+        let path = path.as_ref();
+        let text =
+            fs::read_to_string(path).map_err(|e| ManifestError::Io(path.to_path_buf(), e))?;
+
+        Self::parse(&text, path.parent().unwrap_or(Path::new("")))
+    }
+
+    /// Parses a manifest, joining every input path onto `base`.
+    pub fn parse(text: &str, base: &Path) -> Result<Self, ManifestError> {
+        let table: Table = text.parse().map_err(ManifestError::Toml)?;
+
+        let entries = match table.get("answers") {
+            None => return Ok(Self::default()),
+            Some(Value::Array(entries)) => entries,
+            Some(_) => {
+                return Err(ManifestError::Invalid {
+                    index: 0,
+                    reason: "`answers` must be an array of tables".to_string(),
+                });
+            }
+        };
+
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                parse_entry(entry, base).map_err(|reason| ManifestError::Invalid { index, reason })
+            })
+            .collect::<Result<_, _>>()
+            .map(Manifest)
+    }
+}
+
+fn parse_entry(entry: &Value, base: &Path) -> Result<Expected, String> {
+    let entry = entry.as_table().ok_or("expected a table")?;
+    let year = match entry.get("year") {
+        None => y2021::YEAR,
+        Some(year) => year
+            .as_integer()
+            .and_then(|year| u16::try_from(year).ok())
+            .ok_or("expected a `year` number")?,
+    };
+    let day = entry
+        .get("day")
+        .and_then(Value::as_integer)
+        .and_then(|day| u8::try_from(day).ok())
+        .ok_or("expected a `day` number")?;
+    let input = match entry.get("input") {
+        None => input_path(year, day),
+        Some(input) => input.as_str().ok_or("expected an `input` path")?.into(),
+    };
+
+    let answer = |part| match entry.get(part) {
+        None => Ok(None),
+        Some(Value::Integer(n)) => Ok(Some(n.to_string())),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!("`{part}` must be a number or a string")),
+    };
+
+    Ok(Expected {
+        year,
+        day,
+        input: base.join(input),
+        part1: answer("part1")?,
+        part2: answer("part2")?,
+    })
+}
+
+/// An answer that doesn't match the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    pub expected: String,
+    pub actual: String,
+}
+
+#[derive(Debug)]
+pub enum VerifyError {
+    /// The input isn't checked out, or is an empty placeholder.
+    Missing(PathBuf),
+    Io(io::Error),
+    Check(CheckError),
+    Wrong(Vec<Mismatch>),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::Missing(path) => write!(f, "{} is missing or empty", path.display()),
+            VerifyError::Io(e) => write!(f, "unable to read input: {e}"),
+            VerifyError::Check(e) => write!(f, "{e}"),
+            VerifyError::Wrong(wrong) => {
+                write!(f, "wrong answers")?;
+                for m in wrong {
+                    write!(f, "\n\tpart {}", m.part)?;
+                    if let Some(variant) = m.variant {
+                        write!(f, " - {variant}")?;
+                    }
+                    write!(f, ": expected `{}`, got `{}`", m.expected, m.actual)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+// Multi-line answers are ASCII art, so trailing spaces and blank lines around them don't matter
+fn normalize(answer: &str) -> String {
+    answer
+        .lines()
+        .map(str::trim_end)
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+impl Expected {
+    /// Reads the input without its trailing newlines, an empty input counts as missing.
+    pub fn read_input(&self) -> Result<String, VerifyError> {
+        let mut input = match fs::read_to_string(&self.input) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(VerifyError::Missing(self.input.clone()));
+            }
+            Err(e) => return Err(VerifyError::Io(e)),
+        };
+
+        input.truncate(input.trim_end_matches('\n').len());
+        if input.is_empty() {
+            return Err(VerifyError::Missing(self.input.clone()));
+        }
+
+        Ok(input)
+    }
+
+    /// The expected answer for `part`, if it is checked.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether `actual` is the expected answer for `part`, `None` if it isn't checked.
+    pub fn matches(&self, part: u8, actual: &str) -> Option<bool> {
+        self.answer(part)
+            .map(|expected| normalize(expected) == normalize(actual))
+    }
+
+    /// Runs every variant of the day on the input and compares the answers.
+    ///
+    /// Inputs are private, so a clean clone won't have them, check for [`VerifyError::Missing`] to skip those.
+    pub fn verify(&self) -> Result<(), VerifyError> {
+        let input = self.read_input()?;
+        let results = cross_check(self.year, self.day, &input).map_err(VerifyError::Check)?;
+
+        let wrong: Vec<_> = results
+            .into_iter()
+            .filter(|(entry, actual)| self.matches(entry.part, actual) == Some(false))
+            .map(|(entry, actual)| Mismatch {
+                part: entry.part,
+                variant: entry.variant,
+                expected: self.answer(entry.part).unwrap_or_default().to_string(),
+                actual,
+            })
+            .collect();
+
+        if wrong.is_empty() {
+            Ok(())
+        } else {
+            Err(VerifyError::Wrong(wrong))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[[answers]]
+day = 1
+input = "day1.txt"
+part1 = 7
+part2 = "5"
+
+[[answers]]
+day = 25
+input = "other/day25.txt"
+part1 = 58
+
+[[answers]]
+year = 2022
+day = 3
+"#;
+
+    #[test]
+    pub fn test_parse() {
+        assert_eq!(
+            Manifest::parse(MANIFEST, Path::new("inputs")).unwrap(),
+            Manifest(vec![
+                Expected {
+                    year: 2021,
+                    day: 1,
+                    input: PathBuf::from("inputs/day1.txt"),
+                    part1: Some("7".to_string()),
+                    part2: Some("5".to_string()),
+                },
+                Expected {
+                    year: 2021,
+                    day: 25,
+                    input: PathBuf::from("inputs/other/day25.txt"),
+                    part1: Some("58".to_string()),
+                    part2: None,
+                },
+                Expected {
+                    year: 2022,
+                    day: 3,
+                    input: PathBuf::from("inputs/input/2022/day3.txt"),
+                    part1: None,
+                    part2: None,
+                },
+            ])
+        );
+
+        let err = Manifest::parse("[[answers]]\nday = 1\ninput = 1", Path::new("")).unwrap_err();
+        assert_eq!(err.to_string(), "answers entry 1: expected an `input` path");
+    }
+
+    #[test]
+    pub fn test_missing() {
+        let expected = Expected {
+            year: 2021,
+            day: 1,
+            input: PathBuf::from("does/not/exist.txt"),
+            part1: Some("7".to_string()),
+            part2: None,
+        };
+
+        assert!(matches!(expected.verify(), Err(VerifyError::Missing(_))));
+    }
+
+    #[test]
+    pub fn test_normalize() {
+        assert_eq!(normalize("\n# #  \n ##\n"), "# #\n ##");
+        assert_eq!(normalize("1706"), "1706");
+    }
+
+    #[test]
+    pub fn test_answers() {
+        let manifest =
+            Manifest::load(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml")).unwrap();
+
+        let failures = manifest
+            .0
+            .iter()
+            .filter_map(|e| match e.verify() {
+                Ok(()) => None,
+                Err(err @ VerifyError::Missing(_)) => {
+                    eprintln!("skipping day {}: {err}", e.day);
+                    None
+                }
+                Err(err) => Some(format!("day {} ({}): {err}", e.day, e.input.display())),
+            })
+            .collect_vec();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
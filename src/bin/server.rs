@@ -0,0 +1,30 @@
+use std::{env, net::TcpListener, process::ExitCode};
+
+use advent_of_code_2021::server;
+
+const USAGE: &str = "usage: aoc2021-server [address, defaults to 127.0.0.1:8021]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let addr = match &args[..] {
+        [] => "127.0.0.1:8021",
+        [addr] => addr.as_str(),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let listener = match TcpListener::bind(addr) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("unable to listen on {addr}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("listening on http://{addr}");
+
+    server::serve(listener);
+
+    ExitCode::SUCCESS
+}
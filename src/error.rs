@@ -0,0 +1,138 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error produced by a generator when the puzzle input is malformed.
+///
+/// `line` and `column` are 1-based and point at the start of `text`, the
+/// offending piece of input. They are `0` when the location is unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {} (`{}`)",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The complete input of a day, used to locate the slices that fail to parse.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    // Works out the (line, column) of `text` from where it points into the input
+    fn locate(&self, text: &str) -> (usize, usize) {
+        let base = self.input.as_ptr() as usize;
+        let ptr = text.as_ptr() as usize;
+
+        if ptr < base || ptr + text.len() > base + self.input.len() {
+            return (0, 0);
+        }
+
+        let before = &self.input[..ptr - base];
+        let line = before.bytes().filter(|&b| b == b'\n').count() + 1;
+        let start = before.rfind('\n').map_or(0, |n| n + 1);
+
+        (line, before[start..].chars().count() + 1)
+    }
+
+    /// Creates an error pointing at `text`, which should be a slice of the input.
+    pub fn error(&self, text: &str, reason: impl Into<String>) -> ParseError {
+        let (line, column) = self.locate(text);
+
+        ParseError {
+            day: self.day,
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Parses `text` with [`FromStr`], reporting failures at `text`.
+    pub fn parse<T>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        text.parse()
+            .map_err(|e: T::Err| self.error(text, e.to_string()))
+    }
+
+    /// Parses every character of `line` as a decimal digit.
+    pub fn digits<'b>(
+        &'b self,
+        line: &'b str,
+    ) -> impl Iterator<Item = Result<u8, ParseError>> + 'b {
+        line.char_indices().map(move |(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| self.error(&line[i..i + c.len_utf8()], "expected a digit"))
+        })
+    }
+
+    /// Unwraps `value`, reporting `None` as an error at `text`.
+    pub fn expect<T>(&self, value: Option<T>, text: &str, reason: &str) -> Result<T, ParseError> {
+        value.ok_or_else(|| self.error(text, reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_locate() {
+        let input = "abc\ndef ghi\n\njkl";
+        let src = Source::new(1, input);
+
+        assert_eq!(src.locate(&input[0..3]), (1, 1));
+        assert_eq!(src.locate(&input[8..11]), (2, 5));
+        assert_eq!(src.locate(&input[13..]), (4, 1));
+        assert_eq!(src.locate("elsewhere"), (0, 0));
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let input = "12\n3x";
+        let src = Source::new(7, input);
+        let (a, b) = input.split_once('\n').unwrap();
+
+        assert_eq!(src.parse::<usize>(a), Ok(12));
+        assert_eq!(
+            src.parse::<usize>(b),
+            Err(ParseError {
+                day: 7,
+                line: 2,
+                column: 1,
+                text: "3x".to_string(),
+                reason: "invalid digit found in string".to_string(),
+            })
+        );
+        assert_eq!(
+            src.parse::<usize>(b).unwrap_err().to_string(),
+            "day 7, line 2, column 1: invalid digit found in string (`3x`)"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! The C ABI of the `cdylib`, declared in `include/aoc2021.h`.
+//!
+//! Every function reports failures through its return code, panics included, so nothing unwinds
+//! into the caller. That needs `panic = "unwind"`, which the `ffi` profile has and `release` doesn't.
+
+use std::{
+    ffi::{c_char, c_int},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice, str,
+};
+
+use crate::{solution::find, y2021::YEAR};
+
+/// The answer was written to `out_buf`.
+pub const AOC2021_OK: c_int = 0;
+/// `input` or `out_buf` was null.
+pub const AOC2021_ERR_NULL: c_int = -1;
+/// The input isn't valid UTF-8.
+pub const AOC2021_ERR_UTF8: c_int = -2;
+/// There is no solution for the day and part.
+pub const AOC2021_ERR_UNKNOWN: c_int = -3;
+/// The input failed to parse, the reason is written to `out_buf`.
+pub const AOC2021_ERR_PARSE: c_int = -4;
+/// `out_buf` is too small for the answer and its terminating NUL.
+pub const AOC2021_ERR_BUFFER: c_int = -5;
+/// The solver panicked.
+pub const AOC2021_ERR_PANIC: c_int = -6;
+/// The input parsed, but has no answer.
+pub const AOC2021_ERR_NO_ANSWER: c_int = -7;
+
+// Copies `text` and a NUL into `out`, truncating it if `truncate`, otherwise failing if it doesn't fit
+fn write_out(out: &mut [u8], text: &str, truncate: bool) -> Result<(), c_int> {
+    let len = if text.len() < out.len() {
+        text.len()
+    } else if truncate && !out.is_empty() {
+        // Back up to a char boundary so the message stays valid UTF-8
+        (0..out.len())
+            .rev()
+            .find(|&i| text.is_char_boundary(i))
+            .unwrap_or(0)
+    } else {
+        return Err(AOC2021_ERR_BUFFER);
+    };
+
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+    out[len] = 0;
+
+    Ok(())
+}
+
+fn solve(day: u8, part: u8, input: &[u8], out: &mut [u8]) -> Result<(), c_int> {
+    let input = str::from_utf8(input).map_err(|_| AOC2021_ERR_UTF8)?;
+    let entry = find(YEAR, day, part, None).ok_or(AOC2021_ERR_UNKNOWN)?;
+
+    match entry.run(input.trim_end_matches('\n')) {
+        Ok(Some(answer)) => write_out(out, &answer, false),
+        Ok(None) => Err(AOC2021_ERR_NO_ANSWER),
+        Err(e) => {
+            write_out(out, &e.to_string(), true)?;
+            Err(AOC2021_ERR_PARSE)
+        }
+    }
+}
+
+/// Solves `part` of `day` of 2021 for the `len` bytes of UTF-8 at `input`, writing the answer to
+/// `out_buf` as a NUL-terminated string of at most `out_len` bytes.
+///
+/// Returns [`AOC2021_OK`] or one of the `AOC2021_ERR_*` codes, on [`AOC2021_ERR_PARSE`] `out_buf`
+/// holds the reason instead, truncated to fit.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, and `out_buf` to `out_len` writable bytes. Neither
+/// may be null, even when the length is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc2021_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    if input.is_null() || out_buf.is_null() {
+        return AOC2021_ERR_NULL;
+    }
+
+    // SAFETY: the caller guarantees both buffers are valid for their lengths
+    let (input, out) = unsafe {
+        (
+            slice::from_raw_parts(input, len),
+            slice::from_raw_parts_mut(out_buf.cast::<u8>(), out_len),
+        )
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, input, out))) {
+        Ok(Ok(())) => AOC2021_OK,
+        Ok(Err(code)) => code,
+        Err(_) => AOC2021_ERR_PANIC,
+    }
+}
+
+/// A static, NUL-terminated description of a code returned by [`aoc2021_solve`].
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc2021_strerror(code: c_int) -> *const c_char {
+    let message: &'static [u8] = match code {
+        AOC2021_OK => b"ok\0",
+        AOC2021_ERR_NULL => b"null pointer\0",
+        AOC2021_ERR_UTF8 => b"input is not valid UTF-8\0",
+        AOC2021_ERR_UNKNOWN => b"no solution for that day and part\0",
+        AOC2021_ERR_PARSE => b"input failed to parse\0",
+        AOC2021_ERR_BUFFER => b"output buffer too small\0",
+        AOC2021_ERR_PANIC => b"solver panicked\0",
+        AOC2021_ERR_NO_ANSWER => b"input has no answer\0",
+        _ => return ptr::null(),
+    };
+
+    message.as_ptr().cast()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn call(day: u8, part: u8, input: &[u8], out_len: usize) -> (c_int, String) {
+        let mut out = vec![b'x' as c_char; out_len.max(1)];
+        let code = unsafe {
+            aoc2021_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                out_len,
+            )
+        };
+        let text = if code == AOC2021_OK || code == AOC2021_ERR_PARSE {
+            unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_string_lossy()
+                .into_owned()
+        } else {
+            String::new()
+        };
+
+        (code, text)
+    }
+
+    const SAMPLE: &[u8] = b"199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    pub fn test_solve() {
+        assert_eq!(call(1, 1, SAMPLE, 16), (AOC2021_OK, "7".to_string()));
+        assert_eq!(call(1, 2, SAMPLE, 2), (AOC2021_OK, "5".to_string()));
+        assert_eq!(call(1, 2, SAMPLE, 1).0, AOC2021_ERR_BUFFER);
+    }
+
+    #[test]
+    pub fn test_errors() {
+        assert_eq!(call(26, 1, SAMPLE, 16).0, AOC2021_ERR_UNKNOWN);
+        assert_eq!(call(25, 2, SAMPLE, 16).0, AOC2021_ERR_UNKNOWN);
+        assert_eq!(call(1, 1, b"\xff", 16).0, AOC2021_ERR_UTF8);
+
+        let (code, message) = call(1, 1, b"199\nabc", 20);
+        assert_eq!(code, AOC2021_ERR_PARSE);
+        assert_eq!(message, "day 1, line 2, colu");
+
+        // Valid input, but the sea cucumbers never stop moving
+        assert_eq!(call(25, 1, b">.", 16).0, AOC2021_ERR_NO_ANSWER);
+
+        let mut out = [0; 4];
+        let code = unsafe { aoc2021_solve(1, 1, ptr::null(), 0, out.as_mut_ptr(), out.len()) };
+        assert_eq!(code, AOC2021_ERR_NULL);
+    }
+
+    #[test]
+    pub fn test_strerror() {
+        let message = unsafe { CStr::from_ptr(aoc2021_strerror(AOC2021_ERR_BUFFER)) };
+
+        assert_eq!(message.to_str(), Ok("output buffer too small"));
+        assert!(aoc2021_strerror(1).is_null());
+    }
+
+    // The header is only regenerated on request, so make sure it hasn't fallen behind
+    #[test]
+    pub fn test_header() {
+        let header = include_str!("../include/aoc2021.h");
+
+        for (name, value) in [
+            ("AOC2021_OK", AOC2021_OK),
+            ("AOC2021_ERR_NULL", AOC2021_ERR_NULL),
+            ("AOC2021_ERR_UTF8", AOC2021_ERR_UTF8),
+            ("AOC2021_ERR_UNKNOWN", AOC2021_ERR_UNKNOWN),
+            ("AOC2021_ERR_PARSE", AOC2021_ERR_PARSE),
+            ("AOC2021_ERR_BUFFER", AOC2021_ERR_BUFFER),
+            ("AOC2021_ERR_PANIC", AOC2021_ERR_PANIC),
+            ("AOC2021_ERR_NO_ANSWER", AOC2021_ERR_NO_ANSWER),
+        ] {
+            assert!(
+                header.contains(&format!("#define {name} {value}")),
+                "{name} is out of date"
+            );
+        }
+
+        assert!(header.contains("int aoc2021_solve(uint8_t day,"));
+        assert!(header.contains("const char *aoc2021_strerror(int code);"));
+    }
+
+    #[cfg(feature = "header")]
+    #[test]
+    pub fn test_generated_header() {
+        assert_eq!(
+            include_str!(concat!(env!("OUT_DIR"), "/aoc2021.h")),
+            include_str!("../include/aoc2021.h"),
+            "include/aoc2021.h is out of date, regenerate it with \
+             `AOC2021_UPDATE_HEADER=1 cargo build --features header`"
+        );
+    }
+}
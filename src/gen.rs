@@ -0,0 +1,816 @@
+use std::iter;
+
+use itertools::Itertools;
+use rand::{
+    RngExt, SeedableRng,
+    rngs::ChaCha8Rng,
+    seq::{IndexedRandom, SliceRandom, index},
+};
+
+use crate::{
+    grid::Grid,
+    y2021::{self, day23},
+};
+
+/// Produces random, valid puzzle inputs for a single day.
+///
+/// What `size` measures depends on the day, see the matching `dayNN` function.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// A size close to the real puzzle input.
+    pub default_size: usize,
+    generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Generates an input, the same seed and size always give the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut ChaCha8Rng::seed_from_u64(seed), size)
+    }
+}
+
+macro_rules! generators {
+    ($(($day:literal, $f:ident, $size:literal)),* $(,)?) => {
+        [$(Generator { year: y2021::YEAR, day: $day, default_size: $size, generate: $f }),*]
+    };
+}
+
+static GENERATORS: [Generator; 25] = generators![
+    (1, day01, 2000),
+    (2, day02, 1000),
+    (3, day03, 1000),
+    (4, day04, 100),
+    (5, day05, 500),
+    (6, day06, 300),
+    (7, day07, 1000),
+    (8, day08, 200),
+    (9, day09, 100),
+    (10, day10, 100),
+    (11, day11, 10),
+    (12, day12, 6),
+    (13, day13, 800),
+    (14, day14, 20),
+    (15, day15, 100),
+    (16, day16, 60),
+    (17, day17, 1),
+    (18, day18, 100),
+    (19, day19, 30),
+    (20, day20, 100),
+    (21, day21, 2),
+    (22, day22, 420),
+    (23, day23, 8),
+    (24, day24, 14),
+    (25, day25, 137),
+];
+
+/// Every day's generator, ordered by year and day.
+pub fn generators() -> &'static [Generator] {
+    &GENERATORS
+}
+
+/// The generator for `day` of `year`, if it has one.
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    generators().iter().find(|g| (g.year, g.day) == (year, day))
+}
+
+/// `size` depths, drifting deeper like the real sonar sweep.
+pub fn day01(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut depth: usize = rng.random_range(100..200);
+
+    (0..size)
+        .map(|_| {
+            depth = (depth + rng.random_range(0..30)).saturating_sub(rng.random_range(0..20));
+            depth
+        })
+        .join("\n")
+}
+
+/// `size` commands, the answers to both parts always fit in an `i32`.
+pub fn day02(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const LIMIT: i64 = i32::MAX as i64;
+    let (mut hor, mut aim, mut depth) = (0i64, 0i64, 0i64);
+
+    (0..size)
+        .map(|_| {
+            let amount = rng.random_range(1..10);
+            let dir = *["forward", "down", "up"].choose(rng).unwrap();
+            let valid = match dir {
+                "forward" => {
+                    hor + amount <= LIMIT
+                        && (hor + amount) * aim <= LIMIT
+                        && (hor + amount) * (depth + aim * amount) <= LIMIT
+                }
+                "down" => hor * (aim + amount) <= LIMIT,
+                _ => aim >= amount,
+            };
+
+            // Nudging the aim is always possible and keeps the submarine below the surface
+            let (dir, amount) = match (valid, aim > 0) {
+                (true, _) => (dir, amount),
+                (false, true) => ("up", 1),
+                (false, false) => ("down", 1),
+            };
+
+            match dir {
+                "forward" => {
+                    hor += amount;
+                    depth += aim * amount;
+                }
+                "down" => aim += amount,
+                _ => aim -= amount,
+            }
+
+            format!("{dir} {amount}")
+        })
+        .join("\n")
+}
+
+/// `size` 12 bit numbers.
+pub fn day03(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{:012b}", rng.random_range(0..1 << 12)))
+        .join("\n")
+}
+
+/// `size` boards, every number is drawn so all of them win.
+pub fn day04(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut draw: Vec<usize> = (0..100).collect();
+    draw.shuffle(rng);
+
+    let boards = (0..size.max(1))
+        .map(|_| {
+            index::sample(rng, 100, 25)
+                .into_vec()
+                .chunks(5)
+                .map(|row| row.iter().map(|n| format!("{n:>2}")).join(" "))
+                .join("\n")
+        })
+        .join("\n\n");
+
+    format!("{}\n\n{boards}", draw.iter().join(","))
+}
+
+/// `size` horizontal, vertical and diagonal vents.
+pub fn day05(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let max = crate::y2021::day05::SIZE as i64 - 1;
+
+    (0..size)
+        .map(|_| {
+            let (x, y) = (rng.random_range(0..=max), rng.random_range(0..=max));
+            let (dx, dy) = *[(1, 0), (0, 1), (1, 1), (1, -1)].choose(rng).unwrap();
+            let sign = if rng.random_bool(0.5) { 1 } else { -1 };
+            let (dx, dy) = (dx * sign, dy * sign);
+
+            // Shorten the line so it stays on the map
+            let room = |p: i64, d: i64| match d {
+                1 => max - p,
+                -1 => p,
+                _ => max,
+            };
+            let len = rng.random_range(10..500).min(room(x, dx)).min(room(y, dy));
+
+            format!("{x},{y} -> {},{}", x + dx * len, y + dy * len)
+        })
+        .join("\n")
+}
+
+/// `size` fish.
+pub fn day06(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| rng.random_range(1..=5)).join(",")
+}
+
+/// `size` crabs, mostly bunched up near the start like the real input.
+pub fn day07(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let max = rng.random_range(1..2000);
+            rng.random_range(0..max)
+        })
+        .join(",")
+}
+
+/// `size` displays, each with its own wiring.
+pub fn day08(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    (0..size.max(1))
+        .map(|_| {
+            let mut wiring: Vec<u8> = (b'a'..=b'g').collect();
+            wiring.shuffle(rng);
+
+            let scramble = |rng: &mut ChaCha8Rng, digit: usize| {
+                let mut segments: Vec<char> = DIGITS[digit]
+                    .bytes()
+                    .map(|b| char::from(wiring[usize::from(b - b'a')]))
+                    .collect();
+                segments.shuffle(rng);
+                segments.into_iter().collect::<String>()
+            };
+
+            let mut patterns: Vec<usize> = (0..10).collect();
+            patterns.shuffle(rng);
+            let before = patterns.iter().map(|&d| scramble(rng, d)).join(" ");
+            let after = (0..4)
+                .map(|_| {
+                    let digit = rng.random_range(0..10);
+                    scramble(rng, digit)
+                })
+                .join(" ");
+
+            format!("{before} | {after}")
+        })
+        .join("\n")
+}
+
+/// A `size` by `size` height map, with the basins walled off by 9s.
+pub fn day09(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(2);
+    let lows = index::sample(rng, size * size, (size * size / 40).max(3))
+        .into_iter()
+        .map(|i| (i / size, i % size))
+        .collect_vec();
+
+    (0..size)
+        .map(|r| {
+            (0..size)
+                .map(|c| {
+                    let mut dists = lows
+                        .iter()
+                        .map(|&(y, x)| y.abs_diff(r) + x.abs_diff(c))
+                        .sorted_unstable();
+                    let (d1, d2) = (dists.next().unwrap(), dists.next().unwrap());
+
+                    // Cells about as far from two low points are the walls between basins
+                    if d2 - d1 <= 1 {
+                        '9'
+                    } else {
+                        char::from(b'0' + (d1 + rng.random_range(0..2)).min(8) as u8)
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/// `size` lines, the first is always incomplete so part 2 has an answer.
+pub fn day10(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    // Completion scores are base 5, so deeper nesting would overflow a usize
+    const DEPTH: usize = 16;
+
+    (0..size.max(1))
+        .map(|i| {
+            let len = rng.random_range(60..110);
+            let mut stack = Vec::new();
+            let mut line = String::new();
+
+            while line.len() < len {
+                if stack.is_empty() || (stack.len() < DEPTH && rng.random_bool(0.6)) {
+                    let &(open, close) = PAIRS.choose(rng).unwrap();
+                    stack.push(close);
+                    line.push(open);
+                } else {
+                    line.extend(stack.pop());
+                }
+            }
+
+            if stack.is_empty() {
+                line.push('(');
+                stack.push(')');
+            }
+
+            if i > 0 && rng.random_bool(0.5) {
+                let expected = stack.last().copied();
+                let wrong = PAIRS.iter().map(|p| p.1).filter(|&c| Some(c) != expected);
+                line.extend(wrong.collect_vec().choose(rng));
+                line.extend((0..rng.random_range(0..10)).map(|_| {
+                    let &(open, close) = PAIRS.choose(rng).unwrap();
+                    if rng.random_bool(0.5) { open } else { close }
+                }));
+            }
+
+            line
+        })
+        .join("\n")
+}
+
+/// The 10 by 10 grid of octopuses, `size` is ignored.
+pub fn day11(rng: &mut ChaCha8Rng, _size: usize) -> String {
+    // Not every grid ends up flashing all at once, so only keep the ones that do
+    loop {
+        let cells = (0..100).map(|_| rng.random_range(0..10)).collect();
+        let grid = Grid::from_vec(10, cells).unwrap();
+        let mut octopuses = grid.clone();
+
+        if (0..1000).any(|_| crate::y2021::day11::step(&mut octopuses) == 100) {
+            return grid.to_string();
+        }
+    }
+}
+
+fn cave_names(rng: &mut ChaCha8Rng, count: usize, letters: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+
+    while names.len() < count {
+        let name: String = (0..2)
+            .map(|_| char::from(*letters.choose(rng).unwrap()))
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+/// `size` small caves, big caves are never connected so the paths are finite.
+pub fn day12(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let lower: Vec<u8> = (b'a'..=b'z').collect();
+    let upper: Vec<u8> = (b'A'..=b'Z').collect();
+    let small = cave_names(rng, size.max(2), &lower);
+    let big = cave_names(rng, (size / 3).max(1), &upper);
+    let caves = small.iter().chain(&big).collect_vec();
+
+    let mut edges = Vec::new();
+    for end in ["start", "end"] {
+        for cave in caves.sample(rng, 2) {
+            edges.push((end, cave.as_str()));
+        }
+    }
+    for cave in &big {
+        for other in small.sample(rng, 3) {
+            edges.push((cave.as_str(), other.as_str()));
+        }
+    }
+    for _ in 0..size {
+        let pair = small.sample(rng, 2).collect_vec();
+        edges.push((pair[0].as_str(), pair[1].as_str()));
+    }
+
+    edges = edges
+        .into_iter()
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .unique()
+        .collect();
+    edges.shuffle(rng);
+
+    edges
+        .into_iter()
+        .map(|(a, b)| {
+            if rng.random_bool(0.5) {
+                format!("{a}-{b}")
+            } else {
+                format!("{b}-{a}")
+            }
+        })
+        .join("\n")
+}
+
+/// `size` dots, folded up by 12 folds into a 40 by 6 code.
+pub fn day13(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut axes = [['x'; 5].as_slice(), &['y'; 7]].concat();
+    axes.shuffle(rng);
+
+    // Work backwards from the code, each fold doubles the size of the paper
+    let (mut width, mut height) = (40, 6);
+    let mut folds = Vec::new();
+    for axis in axes {
+        let len = if axis == 'x' { &mut width } else { &mut height };
+        folds.push((axis, *len));
+        *len = *len * 2 + 1;
+    }
+
+    let dots = (0..size.max(1))
+        .map(|_| {
+            let (mut x, mut y) = (rng.random_range(0..40), rng.random_range(0..6));
+            for &(axis, pos) in &folds {
+                let c = if axis == 'x' { &mut x } else { &mut y };
+                if rng.random_bool(0.5) {
+                    *c = 2 * pos - *c;
+                }
+            }
+
+            (x, y)
+        })
+        .unique()
+        .map(|(x, y)| format!("{x},{y}"))
+        .join("\n");
+
+    let folds = folds
+        .iter()
+        .rev()
+        .map(|(axis, pos)| format!("fold along {axis}={pos}"))
+        .join("\n");
+
+    format!("{dots}\n\n{folds}")
+}
+
+/// A template `size` elements long, with a rule for every pair.
+pub fn day14(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let letters: Vec<char> = ('A'..='Z').collect();
+    let elements = letters.sample(rng, 10).copied().collect_vec();
+
+    let template: String = (0..size.max(2))
+        .map(|_| *elements.choose(rng).unwrap())
+        .collect();
+
+    let mut rules = elements
+        .iter()
+        .cartesian_product(&elements)
+        .map(|(a, b)| format!("{a}{b} -> {}", elements.choose(rng).unwrap()))
+        .collect_vec();
+    rules.shuffle(rng);
+
+    format!("{template}\n\n{}", rules.join("\n"))
+}
+
+/// A `size` by `size` risk map.
+pub fn day15(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(2);
+
+    (0..size)
+        .map(|_| (0..size).map(|_| rng.random_range(1..=9)).join(""))
+        .join("\n")
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: usize, len: usize) {
+    bits.extend((0..len).rev().map(|i| (value >> i) & 1 == 1));
+}
+
+// Returns the bits of a packet and its value, keeping products small enough to never overflow
+fn packet(rng: &mut ChaCha8Rng, budget: &mut usize, depth: usize) -> (Vec<bool>, usize) {
+    const LIMIT: usize = 1 << 40;
+
+    let mut bits = Vec::new();
+    push_bits(&mut bits, rng.random_range(0..8), 3);
+    *budget = budget.saturating_sub(1);
+
+    if *budget == 0 || depth >= 6 || (depth > 0 && rng.random_bool(0.4)) {
+        let max = if rng.random_bool(0.1) {
+            1 << 36
+        } else {
+            1 << 12
+        };
+        let value: usize = rng.random_range(0..max);
+        let groups = (usize::BITS - value.leading_zeros()).div_ceil(4).max(1) as usize;
+
+        push_bits(&mut bits, 4, 3);
+        for i in (0..groups).rev() {
+            bits.push(i > 0);
+            push_bits(&mut bits, value >> (i * 4), 4);
+        }
+
+        return (bits, value);
+    }
+
+    let mut id = *[0, 1, 2, 3, 5, 6, 7].choose(rng).unwrap();
+    let count = if id >= 5 { 2 } else { rng.random_range(1..=4) };
+    let (children, values): (Vec<_>, Vec<_>) =
+        (0..count).map(|_| packet(rng, budget, depth + 1)).unzip();
+
+    let product = values
+        .iter()
+        .try_fold(1usize, |acc, &v| acc.checked_mul(v))
+        .filter(|&p| p <= LIMIT);
+    if id == 1 && product.is_none() {
+        id = 0;
+    }
+
+    let value = match id {
+        0 => values.iter().sum(),
+        1 => product.unwrap(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => usize::from(values[0] > values[1]),
+        6 => usize::from(values[0] < values[1]),
+        _ => usize::from(values[0] == values[1]),
+    };
+
+    let children = children.concat();
+    push_bits(&mut bits, id, 3);
+    if children.len() < 1 << 15 && rng.random_bool(0.5) {
+        bits.push(false);
+        push_bits(&mut bits, children.len(), 15);
+    } else {
+        bits.push(true);
+        push_bits(&mut bits, count, 11);
+    }
+    bits.extend(children);
+
+    (bits, value)
+}
+
+/// A transmission of about `size` packets.
+pub fn day16(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let (bits, _) = packet(rng, &mut size.max(1), 0);
+
+    bits.chunks(4)
+        .map(|chunk| {
+            let n = chunk.iter().fold(0, |n, &b| n * 2 + u32::from(b)) << (4 - chunk.len());
+            char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// A target area that can always be hit from above, `size` is ignored.
+pub fn day17(rng: &mut ChaCha8Rng, _size: usize) -> String {
+    // Some x velocity has to come to a stop inside the target for part 1 to have an answer
+    let stop = rng.random_range(8..=20);
+    let x = stop * (stop + 1) / 2;
+    let (min_x, max_x) = (x - rng.random_range(0..=10), x + rng.random_range(0..=30));
+
+    let min_y = -rng.random_range(50..=200);
+    let max_y = min_y + rng.random_range(5..=20);
+
+    format!("target area: x={min_x}..{max_x}, y={min_y}..{max_y}")
+}
+
+// Snailfish numbers in the input are already reduced, so no pair is nested inside four others
+fn snailfish(rng: &mut ChaCha8Rng, depth: usize) -> String {
+    if depth == 0 || (depth < 4 && rng.random_bool(0.6)) {
+        format!(
+            "[{},{}]",
+            snailfish(rng, depth + 1),
+            snailfish(rng, depth + 1)
+        )
+    } else {
+        rng.random_range(0..10).to_string()
+    }
+}
+
+/// `size` snailfish numbers.
+pub fn day18(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size.max(2)).map(|_| snailfish(rng, 0)).join("\n")
+}
+
+type Vec3 = [i32; 3];
+
+// A random rotation, as a permutation of the axes and their signs, with a determinant of 1
+fn rotation(rng: &mut ChaCha8Rng) -> impl Fn(Vec3) -> Vec3 + use<> {
+    let mut axes = [0, 1, 2];
+    axes.shuffle(rng);
+    let mut signs = [1, 1, 1].map(|s| if rng.random_bool(0.5) { s } else { -s });
+
+    let inversions = (0..3)
+        .tuple_combinations()
+        .filter(|&(i, j)| axes[i] > axes[j])
+        .count();
+    let parity = if inversions % 2 == 0 { 1 } else { -1 };
+    if parity * signs.iter().product::<i32>() < 0 {
+        signs[2] = -signs[2];
+    }
+
+    move |v| [0, 1, 2].map(|i| signs[i] * v[axes[i]])
+}
+
+/// `size` scanners, each overlapping another one by at least 12 beacons.
+pub fn day19(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut scanners: Vec<Vec3> = vec![[0, 0, 0]];
+    let mut beacons: Vec<Vec3> = (0..15)
+        .map(|_| [0; 3].map(|_| rng.random_range(-1000..=1000)))
+        .collect();
+
+    while scanners.len() < size.max(3) {
+        let parent = *scanners.choose(rng).unwrap();
+        let pos = parent.map(|c| c + rng.random_range(-1200..=1200));
+
+        // Keeping the scanners spread out stops the reports from overlapping too much
+        if scanners
+            .iter()
+            .any(|s| (0..3).all(|i| s[i].abs_diff(pos[i]) < 1000))
+        {
+            continue;
+        }
+
+        // The beacons in the overlap are what lets the scanners be matched up
+        let (lo, hi) = (
+            [0, 1, 2].map(|i| parent[i].max(pos[i]) - 1000),
+            [0, 1, 2].map(|i| parent[i].min(pos[i]) + 1000),
+        );
+        for _ in 0..12 {
+            beacons.push([0, 1, 2].map(|i| rng.random_range(lo[i]..=hi[i])));
+        }
+        for _ in 0..3 {
+            beacons.push(pos.map(|c| rng.random_range(c - 1000..=c + 1000)));
+        }
+
+        scanners.push(pos);
+    }
+
+    beacons.sort_unstable();
+    beacons.dedup();
+
+    scanners
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| {
+            let rotate = rotation(rng);
+            let mut report = beacons
+                .iter()
+                .map(|b| [0, 1, 2].map(|k| b[k] - pos[k]))
+                .filter(|b| b.iter().all(|c| c.abs() <= 1000))
+                .map(|b| if i == 0 { b } else { rotate(b) })
+                .collect_vec();
+            report.shuffle(rng);
+
+            iter::once(format!("--- scanner {i} ---"))
+                .chain(report.iter().map(|[x, y, z]| format!("{x},{y},{z}")))
+                .join("\n")
+        })
+        .join("\n\n")
+}
+
+fn pixels(rng: &mut ChaCha8Rng, len: usize) -> Vec<bool> {
+    (0..len).map(|_| rng.random_bool(0.5)).collect()
+}
+
+fn pixel_string(pixels: &[bool]) -> String {
+    pixels
+        .iter()
+        .map(|&lit| if lit { '#' } else { '.' })
+        .collect()
+}
+
+/// A `size` by `size` image.
+pub fn day20(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut algorithm = pixels(rng, 512);
+
+    // If the dark infinite background lights up, it has to go dark again on the next step
+    if algorithm[0] {
+        algorithm[511] = false;
+    }
+
+    let image = (0..size.max(1))
+        .map(|_| pixel_string(&pixels(rng, size.max(1))))
+        .join("\n");
+
+    format!("{}\n\n{image}", pixel_string(&algorithm))
+}
+
+/// Two starting positions, `size` is ignored.
+pub fn day21(rng: &mut ChaCha8Rng, _size: usize) -> String {
+    (1..=2)
+        .map(|n| format!("Player {n} starting position: {}", rng.random_range(1..=10)))
+        .join("\n")
+}
+
+/// `size` reboot steps, starting with a few inside the initialization area.
+pub fn day22(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let init = (size / 21).max(1);
+
+    (0..size.max(1))
+        .map(|i| {
+            let kind = if i == 0 || rng.random_bool(0.6) {
+                "on"
+            } else {
+                "off"
+            };
+            let mut cuboid = || {
+                [0; 3].map(|_| {
+                    if i < init {
+                        let min = rng.random_range(-50..=40);
+                        (min, (min + rng.random_range(5..=40)).min(50))
+                    } else {
+                        let min = rng.random_range(-100_000..=80_000);
+                        (min, min + rng.random_range(5_000..=40_000))
+                    }
+                })
+            };
+
+            // Like the real input, the later steps stay clear of the initialization area
+            let mut ranges = cuboid();
+            while i >= init && ranges.iter().all(|&(min, max)| min <= 50 && max >= -50) {
+                ranges = cuboid();
+            }
+            let [x, y, z] = ranges;
+
+            format!(
+                "{kind} x={}..{},y={}..{},z={}..{}",
+                x.0, x.1, y.0, y.1, z.0, z.1
+            )
+        })
+        .join("\n")
+}
+
+/// A shuffled burrow that both parts can sort, `size` is ignored.
+pub fn day23(rng: &mut ChaCha8Rng, _size: usize) -> String {
+    let mut amphipods = *b"AABBCCDD";
+
+    loop {
+        amphipods.shuffle(rng);
+        let [a, b, c, d, e, f, g, h] = amphipods.map(char::from);
+        let input = format!(
+            "#############\n#...........#\n###{a}#{b}#{c}#{d}###\n  #{e}#{f}#{g}#{h}#\n  #########"
+        );
+
+        // Some layouts block the deeper burrow in for good, roll again rather than give no answer
+        let solvable = day23::generator1(&input).is_ok_and(|map| day23::part1(&map).is_some())
+            && day23::generator2(&input).is_ok_and(|map| day23::part2(&map).is_some());
+        if solvable {
+            return input;
+        }
+    }
+}
+
+/// A MONAD program with at least one valid model number, `size` is ignored.
+pub fn day24(rng: &mut ChaCha8Rng, _size: usize) -> String {
+    let mut stack = Vec::new();
+    let mut pushes = 7;
+
+    (0..14)
+        .map(|_| {
+            let (div, add_x, add_y) = if pushes > 0 && (stack.is_empty() || rng.random_bool(0.5)) {
+                pushes -= 1;
+                let add_y = rng.random_range(1..=16);
+                stack.push(add_y);
+                (1, rng.random_range(10..=16), add_y)
+            } else {
+                // The two digits of a push and pop pair can differ by at most 8
+                let pushed = stack.pop().unwrap();
+                (
+                    26,
+                    rng.random_range(-8..=8) - pushed,
+                    rng.random_range(1..=16),
+                )
+            };
+
+            format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {div}\nadd x {add_x}\neql x w\neql x 0\n\
+                 mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {add_y}\n\
+                 mul y x\nadd z y"
+            )
+        })
+        .join("\n")
+}
+
+/// A `size` row map that always comes to a stop.
+pub fn day25(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let (height, width) = (size.max(2), size.max(2) + 2);
+
+    // A full row of east facing and a full column of south facing cucumbers can never move,
+    // every other cucumber eventually piles up against them
+    let (wall_r, wall_c) = (rng.random_range(0..height), rng.random_range(0..width));
+
+    (0..height)
+        .map(|r| {
+            (0..width)
+                .map(|c| {
+                    if c == wall_c {
+                        'v'
+                    } else if r == wall_r {
+                        '>'
+                    } else {
+                        *['>', 'v', '.', '.'].choose(rng).unwrap()
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::cross_check;
+
+    #[test]
+    pub fn test_reproducible() {
+        for g in generators() {
+            assert_eq!(g.generate(1, g.default_size), g.generate(1, g.default_size));
+        }
+
+        assert_ne!(
+            day01(&mut ChaCha8Rng::seed_from_u64(1), 10),
+            day01(&mut ChaCha8Rng::seed_from_u64(2), 10)
+        );
+        assert_eq!(
+            day04(&mut ChaCha8Rng::seed_from_u64(1), 3)
+                .split("\n\n")
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    pub fn test_day23_solvable() {
+        for seed in [5, 7] {
+            let input = find(2021, 23).unwrap().generate(seed, 8);
+            assert!(day23::part2(&day23::generator2(&input).unwrap()).is_some());
+        }
+    }
+
+    #[test]
+    pub fn test_solvable() {
+        for g in generators() {
+            for seed in 0..3 {
+                let input = g.generate(seed, (g.default_size / 10).max(1));
+                if let Err(e) = cross_check(g.year, g.day, &input) {
+                    panic!("{} day {} seed {seed}: {e}\n{input}", g.year, g.day);
+                }
+            }
+        }
+    }
+}
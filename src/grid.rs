@@ -0,0 +1,332 @@
+//! A flat, row-major 2D grid for the days whose input is a map of characters.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    slice::ChunksExact,
+};
+
+use crate::{
+    error::{ParseError, Source},
+    utils,
+};
+
+/// A `(row, column)` position in a [`Grid`].
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawGrid<T>"))]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// A grid as serialized, checked by `from_vec` before it's used
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawGrid<T>> for Grid<T> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawGrid<T>) -> Result<Self, Self::Error> {
+        Grid::from_vec(raw.width, raw.cells)
+            .filter(|grid| grid.height == raw.height)
+            .ok_or("expected `height` whole rows of `width` cells")
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `width` is 0, which [`from_vec`](Self::from_vec) refuses too.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "a grid needs at least one column");
+
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Wraps row-major `cells`, or `None` if they don't make whole rows of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses one cell per character, every line must be the same length.
+    ///
+    /// `reason` describes what `cell` accepts, and is reported at the first character it rejects.
+    pub fn parse(
+        src: &Source,
+        text: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        reason: &str,
+    ) -> Result<Self, ParseError> {
+        let width = text.lines().next().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(src.error(text, "expected a grid"));
+        }
+
+        let mut cells = Vec::with_capacity(text.len());
+        for l in text.lines() {
+            let start = cells.len();
+            for (i, c) in l.char_indices() {
+                cells.push(src.expect(cell(c), &l[i..i + c.len_utf8()], reason)?);
+            }
+
+            if cells.len() - start != width {
+                return Err(src.error(l, format!("expected {width} cells")));
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (r, c): Pos) -> Option<&T> {
+        (r < self.height && c < self.width).then(|| &self.cells[r * self.width + c])
+    }
+
+    pub fn get_mut(&mut self, (r, c): Pos) -> Option<&mut T> {
+        (r < self.height && c < self.width).then(|| &mut self.cells[r * self.width + c])
+    }
+
+    /// Moves `(dr, dc)` from `(r, c)`, wrapping around the edges as if the grid were a torus.
+    pub fn wrap(&self, p: Pos, d: (isize, isize)) -> Pos {
+        wrap_in((self.width, self.height), p, d)
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// The up to 4 orthogonal neighbours of `p` that are in bounds.
+    pub fn neighbors(&self, (r, c): Pos) -> impl Iterator<Item = Pos> + use<T> {
+        utils::neighbors(r, c, self.height, self.width)
+    }
+
+    /// The up to 8 neighbours of `p` including diagonals that are in bounds.
+    pub fn neighbors_diag(&self, (r, c): Pos) -> impl Iterator<Item = Pos> + use<T> {
+        utils::neighbors_diag(r, c, self.height, self.width)
+    }
+
+    /// The 4 orthogonal neighbours of `p`, wrapping around the edges.
+    pub fn neighbors_wrapping(&self, p: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        let grid = (self.width, self.height);
+
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .map(move |d| wrap_in(grid, p, d))
+    }
+
+    /// The 8 neighbours of `p` including diagonals, wrapping around the edges.
+    pub fn neighbors_diag_wrapping(&self, p: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        let grid = (self.width, self.height);
+
+        [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ]
+        .into_iter()
+        .map(move |d| wrap_in(grid, p, d))
+    }
+
+    /// The cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+fn wrap_in((width, height): (usize, usize), (r, c): Pos, (dr, dc): (isize, isize)) -> Pos {
+    let wrap = |n: usize, d: isize, max: usize| (n as isize + d).rem_euclid(max as isize) as usize;
+
+    (wrap(r, dr, height), wrap(c, dc, width))
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): Pos) -> &T {
+        assert!(c < self.width, "column {c} out of bounds");
+        &self.cells[r * self.width + c]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Pos) -> &mut T {
+        assert!(c < self.width, "column {c} out of bounds");
+        &mut self.cells[r * self.width + c]
+    }
+}
+
+/// Prints one line per row, with no trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "123\n456";
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(
+            &Source::new(0, input),
+            input,
+            |c| c.to_digit(10),
+            "expected a digit",
+        )
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let grid = digits(SAMPLE).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(Grid::from_vec(3, (1..=6).collect()), Some(grid));
+        assert_eq!(Grid::from_vec(4, (1..=6).collect::<Vec<u32>>()), None);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let err = digits("12\n3x").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.reason.as_str()),
+            (2, 2, "expected a digit")
+        );
+
+        let err = digits("12\n345").unwrap_err();
+        assert_eq!((err.line, err.reason.as_str()), (2, "expected 2 cells"));
+
+        assert_eq!(digits("").unwrap_err().reason, "expected a grid");
+    }
+
+    #[test]
+    pub fn test_neighbors() {
+        let grid = digits(SAMPLE).unwrap();
+
+        assert_eq!(grid.neighbors((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors_diag((0, 1)).count(), 5);
+        assert_eq!(
+            grid.neighbors_wrapping((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (1, 0), (0, 2), (0, 1)]
+        );
+        assert_eq!(grid.neighbors_diag_wrapping((1, 2)).count(), 8);
+        assert_eq!(grid.wrap((1, 2), (1, 1)), (0, 0));
+        assert_eq!(grid.wrap((0, 0), (-4, -7)), (0, 2));
+    }
+
+    #[test]
+    #[should_panic = "a grid needs at least one column"]
+    pub fn test_new_empty() {
+        Grid::new(0, 3, 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let grid = digits(SAMPLE).unwrap();
+        let json = serde_json::to_string(&grid).unwrap();
+
+        assert_eq!(json, r#"{"width":3,"height":2,"cells":[1,2,3,4,5,6]}"#);
+        assert_eq!(serde_json::from_str::<Grid<u32>>(&json).unwrap(), grid);
+
+        for json in [
+            r#"{"width":0,"height":0,"cells":[]}"#,
+            r#"{"width":3,"height":2,"cells":[1,2,3,4,5]}"#,
+            r#"{"width":3,"height":3,"cells":[1,2,3,4,5,6]}"#,
+        ] {
+            assert!(serde_json::from_str::<Grid<u32>>(json).is_err());
+        }
+    }
+
+    #[test]
+    pub fn test_display() {
+        let grid = digits(SAMPLE).unwrap();
+
+        assert_eq!(grid.to_string(), SAMPLE);
+        assert_eq!(grid.map(|n| n * 2).to_string(), "246\n81012");
+        assert_eq!(grid.positions().last(), Some((1, 2)));
+    }
+}
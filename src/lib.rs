@@ -1,30 +1,23 @@
 use aoc_runner_derive::aoc_lib;
-pub mod common;
+pub mod alloc_counter;
+pub mod answers;
+pub mod error;
+pub mod ffi;
+pub mod r#gen;
+pub mod grid;
+mod par;
+pub mod report;
+pub mod scaffold;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod solution;
+pub mod stream;
+pub mod trace;
+pub mod utils;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+pub use error::ParseError;
+pub use solution::{Entry, Solution};
+
+pub mod y2021;
 // Insert before
 aoc_lib! { year = 2021 }
@@ -0,0 +1,481 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+    process::ExitCode,
+    time::Instant,
+};
+
+use advent_of_code_2021::{
+    alloc_counter::CountingAlloc,
+    answers::{Manifest, VerifyError},
+    r#gen::{self, Generator},
+    report, scaffold,
+    solution::{Entry, cross_check, registry},
+    stream, trace,
+    y2021::{self, day02},
+};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+const USAGE: &str =
+    "usage: advent-of-code-2021 [year] day<N> [part<N> [variant]] [input file, or - for stdin]
+       advent-of-code-2021 check [year] day<N> [input file, or - for stdin]
+       advent-of-code-2021 stream [year] day<N> [input file, or - for stdin]
+       advent-of-code-2021 explain [year] day<N> part<N> [input file, or - for stdin]
+       advent-of-code-2021 course plain|aim [day 2 input or script, or - for stdin]
+       advent-of-code-2021 gen [year] day<N> [seed [size]]
+       advent-of-code-2021 verify [manifest, defaults to answers.toml]
+       advent-of-code-2021 report [manifest, defaults to answers.toml]
+       advent-of-code-2021 new [year] day<N> [title]";
+
+struct Args {
+    check: bool,
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    variant: Option<String>,
+    path: Option<String>,
+}
+
+fn parse_number(arg: &str, prefix: &str) -> Option<u8> {
+    arg.strip_prefix(prefix).unwrap_or(arg).parse().ok()
+}
+
+// Years have four digits, so they can't be mistaken for a day without its `day` prefix
+fn parse_year(arg: &str) -> Option<u16> {
+    (arg.len() == 4).then(|| arg.parse().ok())?
+}
+
+// Splits off an optional leading year, which defaults to 2021
+fn split_year(args: &[String]) -> (u16, &[String]) {
+    match args.first().and_then(|arg| parse_year(arg)) {
+        Some(year) => (year, &args[1..]),
+        None => (y2021::YEAR, args),
+    }
+}
+
+fn parse_args(args: &[String]) -> Option<Args> {
+    let mut args = args.iter().peekable();
+    let check = args.next_if(|arg| *arg == "check").is_some();
+    let year = match args.next_if(|arg| parse_year(arg).is_some()) {
+        Some(arg) => parse_year(arg)?,
+        None => y2021::YEAR,
+    };
+    let day = parse_number(args.next()?, "day")?;
+
+    // Checking always runs every part and variant
+    let part = args
+        .peek()
+        .filter(|_| !check)
+        .and_then(|arg| arg.strip_prefix("part"))
+        .and_then(|n| n.parse().ok());
+    let mut variant = None;
+
+    if part.is_some() {
+        args.next();
+
+        // A variant is only recognised if it is registered, anything else is the input path
+        if let Some(arg) = args.next_if(|arg| {
+            registry().iter().any(|e| {
+                (e.year, e.day, Some(e.part), e.variant) == (year, day, part, Some(arg.as_str()))
+            })
+        }) {
+            variant = Some(arg.clone());
+        }
+    }
+
+    let path = args.next().cloned();
+    if args.next().is_some() {
+        return None;
+    }
+
+    Some(Args {
+        check,
+        year,
+        day,
+        part,
+        variant,
+        path,
+    })
+}
+
+fn parse_gen_args(args: &[String]) -> Option<(&'static Generator, u64, usize)> {
+    let (year, args) = split_year(args);
+    let [day, rest @ ..] = args else {
+        return None;
+    };
+    if rest.len() > 2 {
+        return None;
+    }
+
+    let generator = r#gen::find(year, parse_number(day, "day")?)?;
+    let seed = rest.first().map_or(Some(0), |s| s.parse().ok())?;
+    let size = rest
+        .get(1)
+        .map_or(Some(generator.default_size), |s| s.parse().ok())?;
+
+    Some((generator, seed, size))
+}
+
+fn read_input(path: Option<&str>) -> io::Result<String> {
+    let mut input = String::new();
+
+    match path {
+        None | Some("-") => {
+            io::stdin().read_to_string(&mut input)?;
+        }
+        Some(path) => input = fs::read_to_string(path)?,
+    }
+
+    Ok(input)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "gen") {
+        let Some((generator, seed, size)) = parse_gen_args(&args[1..]) else {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        };
+
+        println!("{}", generator.generate(seed, size));
+        return ExitCode::SUCCESS;
+    }
+
+    if args.first().is_some_and(|arg| arg == "stream") {
+        return match split_year(&args[1..]) {
+            (year, [day]) => run_stream(year, day, None),
+            (year, [day, path]) => run_stream(year, day, Some(path)),
+            _ => {
+                eprintln!("{USAGE}");
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    if args.first().is_some_and(|arg| arg == "new") {
+        let (year, rest) = split_year(&args[1..]);
+        let day = rest.first().and_then(|day| parse_number(day, "day"));
+        let Some(day) = day.filter(|_| rest.len() <= 2) else {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        };
+
+        return new_day(year, day, rest.get(1).map(String::as_str));
+    }
+
+    if args.first().is_some_and(|arg| arg == "explain") {
+        // Shares the runner's argument order, but the part is required and there are no variants
+        return match parse_args(&args[1..]) {
+            Some(Args {
+                check: false,
+                year,
+                day,
+                part: Some(part),
+                variant: None,
+                path,
+            }) => explain(year, day, part, path.as_deref()),
+            _ => {
+                eprintln!("{USAGE}");
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    if args.first().is_some_and(|arg| arg == "course") {
+        let model = match args.get(1).map(String::as_str) {
+            Some("plain") => day02::Model::Plain,
+            Some("aim") => day02::Model::Aim,
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            }
+        };
+
+        return match &args[2..] {
+            [] => course(model, None),
+            [path] => course(model, Some(path)),
+            _ => {
+                eprintln!("{USAGE}");
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    if let Some(command @ ("verify" | "report")) = args.first().map(String::as_str) {
+        let run = if command == "verify" {
+            verify
+        } else {
+            run_report
+        };
+
+        return match &args[1..] {
+            [] => run("answers.toml"),
+            [path] => run(path),
+            _ => {
+                eprintln!("{USAGE}");
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    let Some(args) = parse_args(&args) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    if args.check {
+        return check(args.year, args.day, args.path.as_deref());
+    }
+
+    let entries: Vec<&Entry> = registry()
+        .iter()
+        .filter(|e| {
+            (e.year, e.day) == (args.year, args.day)
+                && args.part.is_none_or(|p| p == e.part)
+                && e.variant == args.variant.as_deref()
+        })
+        .collect();
+
+    if entries.is_empty() {
+        eprintln!("no solution for {} day {}", args.year, args.day);
+        return ExitCode::from(2);
+    }
+
+    let input = match read_input(args.path.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("unable to read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = input.trim_end_matches('\n');
+
+    let mut code = ExitCode::SUCCESS;
+    for entry in entries {
+        let start = Instant::now();
+        let runner = match entry.generate(input) {
+            Ok(runner) => runner,
+            Err(e) => {
+                eprintln!("{entry}: FAILED while generating:\n{e}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let inter = Instant::now();
+        let answer = runner.run().unwrap_or_else(|| {
+            code = ExitCode::FAILURE;
+            "no answer".to_string()
+        });
+        println!(
+            "{entry}: {answer}\n\tgenerator: {:?},\n\trunner: {:?}\n",
+            inter - start,
+            inter.elapsed()
+        );
+    }
+
+    code
+}
+
+fn check(year: u16, day: u8, path: Option<&str>) -> ExitCode {
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("unable to read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cross_check(year, day, input.trim_end_matches('\n')) {
+        Ok(results) => {
+            for (entry, answer) in results {
+                println!("{entry}: {answer}");
+            }
+            println!("all variants agree");
+
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("FAILED: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_stream(year: u16, day: &str, path: Option<&str>) -> ExitCode {
+    let Some(day) = parse_number(day, "day") else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    let reader: Box<dyn BufRead> = match path {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("unable to read input: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let start = Instant::now();
+    match stream::solve(year, day, reader) {
+        None => {
+            eprintln!("{year} day {day} can't be streamed");
+            ExitCode::from(2)
+        }
+        Some(Ok((part1, part2))) => {
+            let code = if part1.is_some() && part2.is_some() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            };
+            let [part1, part2] = [part1, part2].map(|a| a.unwrap_or_else(|| "no answer".into()));
+            println!(
+                "Day {day} - Part 1: {part1}\nDay {day} - Part 2: {part2}\n\ttime: {:?}",
+                start.elapsed()
+            );
+            code
+        }
+        Some(Err(e)) => {
+            eprintln!("Day {day}: FAILED: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(year: u16, day: u8, title: Option<&str>) -> ExitCode {
+    match scaffold::scaffold(Path::new("."), year, day, title) {
+        Ok(changed) => {
+            for path in changed {
+                println!("updated {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("unable to create {year} day {day}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn explain(year: u16, day: u8, part: u8, path: Option<&str>) -> ExitCode {
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("unable to read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match trace::explain(year, day, part, input.trim_end_matches('\n')) {
+        None => {
+            eprintln!("{year} day {day} part {part} can't be traced");
+            ExitCode::from(2)
+        }
+        Some(Ok((answer, events))) => {
+            print!("{}", trace::render(&events));
+            println!(
+                "Day {day} - Part {part}: {}",
+                answer.as_deref().unwrap_or("no answer")
+            );
+            if answer.is_some() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Some(Err(e)) => {
+            eprintln!("Day {day}: FAILED while generating:\n{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn course(model: day02::Model, path: Option<&str>) -> ExitCode {
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("unable to read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // The script's own includes are relative to it, or to the current directory for stdin
+    let dir = path
+        .filter(|&path| path != "-")
+        .and_then(|path| Path::new(path).parent())
+        .unwrap_or(Path::new("."));
+    let commands = match day02::script::expand_in(&input, dir) {
+        Ok(commands) => commands,
+        Err(e) => {
+            eprintln!("Day 2: FAILED while expanding the script:\n{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let trajectory = match day02::simulate(&commands, model) {
+        Ok(trajectory) => trajectory,
+        Err(e) => {
+            eprintln!("Day 2: impossible course: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match day02::write_csv(io::stdout().lock(), &commands, &trajectory, model) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("unable to write the course: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load_manifest(path: &str) -> Option<Manifest> {
+    Manifest::load(path)
+        .inspect_err(|e| eprintln!("invalid manifest: {e}"))
+        .ok()
+}
+
+fn run_report(path: &str) -> ExitCode {
+    let Some(manifest) = load_manifest(path) else {
+        return ExitCode::FAILURE;
+    };
+
+    let report = report::run(&manifest);
+    println!("{}", report.to_json());
+
+    if report.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(path: &str) -> ExitCode {
+    let Some(manifest) = load_manifest(path) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut failed = false;
+    for expected in &manifest.0 {
+        let name = format!("Day {} ({})", expected.day, expected.input.display());
+        match expected.verify() {
+            Ok(()) => println!("{name}: ok"),
+            Err(e @ VerifyError::Missing(_)) => println!("{name}: skipped, {e}"),
+            Err(e) => {
+                eprintln!("{name}: FAILED: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
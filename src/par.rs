@@ -0,0 +1,38 @@
+//! Rayon when the `parallel` feature is on, plain iterators otherwise, so the days are written once
+//! against `use crate::par::prelude::*`.
+//!
+//! Only the adapters both kinds of iterator share can be used, plus `find_map_first`, which is
+//! deterministic either way.
+
+pub(crate) mod prelude {
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) use super::SequentialExt;
+    pub(crate) use super::par_iter;
+    #[cfg(feature = "parallel")]
+    pub(crate) use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+}
+
+/// Iterates `items` on the rayon thread pool.
+#[cfg(feature = "parallel")]
+pub(crate) fn par_iter<T: Sync>(items: &[T]) -> rayon::slice::Iter<'_, T> {
+    use rayon::iter::IntoParallelRefIterator;
+
+    items.par_iter()
+}
+
+/// Iterates `items` sequentially, as the `parallel` feature is off.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn par_iter<T: Sync>(items: &[T]) -> std::slice::Iter<'_, T> {
+    items.iter()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) trait SequentialExt: Iterator + Sized {
+    /// Rayon's name for [`Iterator::find_map`].
+    fn find_map_first<B>(mut self, f: impl FnMut(Self::Item) -> Option<B>) -> Option<B> {
+        self.find_map(f)
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: Iterator> SequentialExt for I {}
@@ -0,0 +1,224 @@
+//! A machine-readable report of running every day against the answers manifest.
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::{
+    alloc_counter::Peak,
+    answers::{Expected, Manifest, VerifyError},
+    solution::{Entry, registry},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The manifest has no answer for this part.
+    Unchecked,
+    /// The generator rejected the input, or the part found no answer.
+    Error,
+    /// The input is missing, so nothing ran.
+    Skipped,
+}
+
+/// The result of one day, part and variant.
+///
+/// Timings are in nanoseconds, and the allocation counts are `None` unless the binary installed
+/// [`CountingAlloc`](crate::alloc_counter::CountingAlloc).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+    pub generator_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub peak_allocations: Option<usize>,
+    pub peak_bytes: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct Report {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub records: Vec<Record>,
+}
+
+impl Report {
+    pub fn push(&mut self, record: Record) {
+        match record.status {
+            Status::Pass => self.passed += 1,
+            Status::Fail | Status::Error => self.failed += 1,
+            Status::Skipped => self.skipped += 1,
+            Status::Unchecked => {}
+        }
+
+        self.records.push(record);
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports only contain strings and numbers")
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn record(entry: &Entry, expected: &Expected) -> Record {
+    Record {
+        year: entry.year,
+        day: entry.day,
+        part: entry.part,
+        variant: entry.variant,
+        status: Status::Skipped,
+        answer: None,
+        expected: expected.answer(entry.part).map(str::to_string),
+        error: None,
+        generator_ns: None,
+        solve_ns: None,
+        peak_allocations: None,
+        peak_bytes: None,
+    }
+}
+
+/// Times `entry` on `input` and checks its answer.
+pub fn run_entry(entry: &Entry, input: &str, expected: &Expected) -> Record {
+    let mut record = record(entry, expected);
+
+    let peak = Peak::start();
+    let start = Instant::now();
+    let runner = match entry.generate(input) {
+        Ok(runner) => runner,
+        Err(e) => {
+            record.status = Status::Error;
+            record.error = Some(e.to_string());
+            return record;
+        }
+    };
+
+    let inter = Instant::now();
+    let answer = runner.run();
+    let end = Instant::now();
+    drop(runner);
+
+    record.status = match answer.as_deref().map(|a| expected.matches(entry.part, a)) {
+        Some(Some(true)) => Status::Pass,
+        Some(Some(false)) => Status::Fail,
+        Some(None) => Status::Unchecked,
+        None => {
+            record.error = Some("no answer".to_string());
+            Status::Error
+        }
+    };
+    record.answer = answer;
+    record.generator_ns = Some(nanos(inter - start));
+    record.solve_ns = Some(nanos(end - inter));
+    if let Some(peak) = Peak::since(peak) {
+        record.peak_allocations = Some(peak.allocations);
+        record.peak_bytes = Some(peak.bytes);
+    }
+
+    record
+}
+
+/// Runs every registered day, part and variant against the inputs in `manifest`.
+pub fn run(manifest: &Manifest) -> Report {
+    let mut report = Report::default();
+
+    for expected in &manifest.0 {
+        let entries = registry()
+            .iter()
+            .filter(|e| (e.year, e.day) == (expected.year, expected.day));
+
+        match expected.read_input() {
+            Ok(input) => {
+                for entry in entries {
+                    report.push(run_entry(entry, &input, expected));
+                }
+            }
+            Err(e) => {
+                let status = match e {
+                    VerifyError::Missing(_) => Status::Skipped,
+                    _ => Status::Error,
+                };
+
+                for entry in entries {
+                    report.push(Record {
+                        status,
+                        error: Some(e.to_string()),
+                        ..record(entry, expected)
+                    });
+                }
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::solution::find;
+
+    const SAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+    fn expected(part1: &str) -> Expected {
+        Expected {
+            year: 2021,
+            day: 1,
+            input: PathBuf::from("does/not/exist.txt"),
+            part1: Some(part1.to_string()),
+            part2: None,
+        }
+    }
+
+    #[test]
+    pub fn test_run_entry() {
+        let part1 = find(2021, 1, 1, None).unwrap();
+        let part2 = find(2021, 1, 2, None).unwrap();
+
+        let record = run_entry(part1, SAMPLE, &expected("7"));
+        assert_eq!(record.status, Status::Pass);
+        assert_eq!(record.answer.as_deref(), Some("7"));
+        assert!(record.generator_ns.is_some() && record.solve_ns.is_some());
+        assert!(record.peak_allocations.is_some());
+
+        assert_eq!(
+            run_entry(part1, SAMPLE, &expected("8")).status,
+            Status::Fail
+        );
+        assert_eq!(
+            run_entry(part2, SAMPLE, &expected("7")).status,
+            Status::Unchecked
+        );
+
+        let record = run_entry(part1, "199\nabc", &expected("7"));
+        assert_eq!(record.status, Status::Error);
+        assert!(record.error.unwrap().contains("line 2"));
+    }
+
+    #[test]
+    pub fn test_run() {
+        let report = run(&Manifest(vec![expected("7")]));
+
+        assert_eq!((report.passed, report.failed, report.skipped), (0, 0, 6));
+        assert!(report.records.iter().all(|r| r.status == Status::Skipped));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["skipped"], 6);
+        assert_eq!(json["records"][0]["status"], "skipped");
+        assert_eq!(json["records"][2]["variant"], "fastest");
+        assert_eq!(json["records"][0]["expected"], "7");
+    }
+}
@@ -0,0 +1,394 @@
+//! Creates a new day from `src/template.rs` and registers it everywhere a day is listed.
+
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{solution::input_path, y2021};
+
+/// Marks where new years go in `src/lib.rs` and the registry in `src/solution.rs`, and new days
+/// in a year's module list and registry.
+pub const MARKER: &str = "// Insert before";
+
+/// The year given to `aoc_lib!`, cargo-aoc can only run one so the other years leave out the
+/// `#[aoc]` attributes.
+const CARGO_AOC_YEAR: u16 = y2021::YEAR;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Io(PathBuf, io::Error),
+    /// Advent of Code only has days 1 to 25.
+    Day(u8),
+    /// The day's module already exists, nothing was changed.
+    Exists(PathBuf),
+    /// The file has too few [`MARKER`] lines to insert before.
+    MissingMarker(PathBuf),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Io(path, e) => write!(f, "unable to access {}: {e}", path.display()),
+            ScaffoldError::Day(day) => write!(f, "there is no day {day}, only 1 to 25"),
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::MissingMarker(path) => {
+                write!(f, "{} is missing a `{MARKER}` line", path.display())
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+/// Replaces the `N` and `dayN` placeholders in `template` with `day`, dropping the cargo-aoc
+/// attributes and import unless `cargo_aoc`.
+///
+/// Only whole identifiers are replaced, so `N` inside other names is left alone.
+pub fn instantiate(template: &str, day: u8, cargo_aoc: bool) -> String {
+    let mut res = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find(|c: char| c.is_alphanumeric() || c == '_') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        match &rest[..end] {
+            "N" => res.push_str(&day.to_string()),
+            "dayN" => res.push_str(&format!("day{day}")),
+            ident => res.push_str(ident),
+        }
+        rest = &rest[end..];
+    }
+    res.push_str(rest);
+
+    if cargo_aoc {
+        return res;
+    }
+
+    let res: String = res
+        .lines()
+        .filter(|l| !l.starts_with("#[aoc") && !l.starts_with("use aoc_runner_derive"))
+        .map(|l| format!("{l}\n"))
+        .collect();
+    res.trim_start().to_string()
+}
+
+/// The module of a year with no days yet.
+pub fn year_module(year: u16) -> String {
+    format!(
+        "//! Advent of Code {year}, run through the bundled runner rather than `cargo aoc`.
+
+use crate::solution::{{Entry, entries}};
+
+pub const YEAR: u16 = {year};
+
+{MARKER}
+
+/// The year's entries, ordered by day.
+pub fn registry() -> Vec<Entry> {{
+    [
+        {MARKER}
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}}
+"
+    )
+}
+
+/// Inserts each of `lines` before the matching [`MARKER`] line of `text`, the first line before
+/// the first marker and so on, with the marker's indentation.
+pub fn insert_before_markers(text: &str, lines: &[&str]) -> Option<String> {
+    let mut res: Vec<String> = text.lines().map(str::to_string).collect();
+    let markers: Vec<usize> = res
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.trim() == MARKER)
+        .map(|(i, _)| i)
+        .collect();
+    if markers.len() < lines.len() {
+        return None;
+    }
+
+    // Backwards, so inserting doesn't move the markers still to come
+    for (&at, line) in markers.iter().zip(lines).rev() {
+        let indent = &res[at][..res[at].len() - res[at].trim_start().len()];
+        res.insert(at, format!("{indent}{line}"));
+    }
+
+    Some(res.join("\n") + "\n")
+}
+
+// The year of a `## 2021` heading
+fn heading_year(line: &str) -> Option<u16> {
+    line.strip_prefix("## ")?.parse().ok()
+}
+
+/// Adds a link to the day's module to the README, in order under the year's heading, which is
+/// added before the next year or the first other heading if it isn't there yet.
+pub fn add_readme_link(readme: &str, year: u16, day: u8, title: Option<&str>) -> String {
+    let link = match title {
+        Some(title) => format!("- [Day {day}: {title}](src/y{year}/day{day:02}.rs)"),
+        None => format!("- [Day {day}](src/y{year}/day{day:02}.rs)"),
+    };
+    let linked_day = |l: &str| {
+        let rest = l.strip_prefix("- [Day ")?;
+        let end = rest.find(|c: char| !c.is_ascii_digit())?;
+        rest[..end].parse::<u8>().ok()
+    };
+
+    let mut lines: Vec<&str> = readme.lines().collect();
+    let Some(heading) = lines.iter().position(|l| heading_year(l) == Some(year)) else {
+        let at = lines
+            .iter()
+            .position(|l| l.starts_with("## ") && heading_year(l).is_none_or(|y| y > year))
+            .unwrap_or(lines.len());
+        let heading = format!("## {year}");
+        lines.splice(at..at, [heading.as_str(), "", &link, ""]);
+
+        return lines.join("\n") + "\n";
+    };
+
+    let end = lines[heading + 1..]
+        .iter()
+        .position(|l| l.starts_with("## "))
+        .map_or(lines.len(), |i| heading + 1 + i);
+    let days: Vec<(usize, u8)> = (heading + 1..end)
+        .filter_map(|i| Some((i, linked_day(lines[i])?)))
+        .collect();
+
+    let at = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => days.last().map_or(heading + 2, |&(i, _)| i + 1),
+    };
+    lines.insert(at, &link);
+
+    lines.join("\n") + "\n"
+}
+
+/// Appends an entry for the day's input to the answers manifest, with no answers to check yet.
+pub fn add_answers_entry(answers: &str, year: u16, day: u8) -> String {
+    format!(
+        "{}\n\n[[answers]]\nyear = {year}\nday = {day}\ninput = \"{}\"\n",
+        answers.trim_end(),
+        input_path(year, day).display()
+    )
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), ScaffoldError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| ScaffoldError::Io(dir.to_path_buf(), e))?;
+    }
+
+    fs::write(path, text).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+}
+
+// Inserts `lines` before the markers of `text`, which was read from `path`
+fn insert(path: PathBuf, text: &str, lines: &[&str]) -> Result<(PathBuf, String), ScaffoldError> {
+    match insert_before_markers(text, lines) {
+        Some(text) => Ok((path, text)),
+        None => Err(ScaffoldError::MissingMarker(path)),
+    }
+}
+
+/// Creates `src/yYYYY/dayNN.rs` under `root` and registers it in the year's module, the README and
+/// `answers.toml`, returning the files it changed. The first day of a new year also creates the
+/// year's module and registers it in `src/lib.rs` and `src/solution.rs`.
+///
+/// Every file is read and checked before any is written, so a missing marker leaves the tree as
+/// it was.
+pub fn scaffold(
+    root: &Path,
+    year: u16,
+    day: u8,
+    title: Option<&str>,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::Day(day));
+    }
+
+    let dir = root.join(format!("src/y{year}"));
+    let module = dir.join(format!("day{day:02}.rs"));
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+
+    let template = read(&root.join("src/template.rs"))?;
+    let mut changes = vec![(module, instantiate(&template, day, year == CARGO_AOC_YEAR))];
+
+    let year_mod = dir.join("mod.rs");
+    let year_text = if year_mod.exists() {
+        read(&year_mod)?
+    } else {
+        for (path, line) in [
+            ("src/lib.rs", format!("pub mod y{year};")),
+            ("src/solution.rs", format!("crate::y{year}::registry(),")),
+        ] {
+            let path = root.join(path);
+            let text = read(&path)?;
+            changes.push(insert(path, &text, &[&line])?);
+        }
+
+        year_module(year)
+    };
+    let lines = [
+        format!("pub mod day{day:02};"),
+        format!("entries::<day{day:02}::Solver>(),"),
+    ];
+    changes.push(insert(
+        year_mod,
+        &year_text,
+        &lines.each_ref().map(String::as_str),
+    )?);
+
+    let readme = root.join("README.md");
+    let text = add_readme_link(&read(&readme)?, year, day, title);
+    changes.push((readme, text));
+
+    let answers = root.join("answers.toml");
+    let text = add_answers_entry(&read(&answers)?, year, day);
+    changes.push((answers, text));
+
+    for (path, text) in &changes {
+        write(path, text)?;
+    }
+
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_instantiate() {
+        let template = "use aoc_runner_derive::aoc;\n\n#[aoc(dayN, part1)]\nconst DAY: u8 = N;\nlet NN = Source::new(N, input);\n";
+
+        assert_eq!(
+            instantiate(template, 7, true),
+            "use aoc_runner_derive::aoc;\n\n#[aoc(day7, part1)]\nconst DAY: u8 = 7;\nlet NN = Source::new(7, input);\n"
+        );
+        assert_eq!(
+            instantiate(template, 7, false),
+            "const DAY: u8 = 7;\nlet NN = Source::new(7, input);\n"
+        );
+    }
+
+    #[test]
+    pub fn test_insert_before_markers() {
+        let text = "a\n// Insert before\n[\n    b,\n    // Insert before\n]\n";
+
+        assert_eq!(
+            insert_before_markers(text, &["c", "d,"]).as_deref(),
+            Some("a\nc\n// Insert before\n[\n    b,\n    d,\n    // Insert before\n]\n")
+        );
+        assert_eq!(insert_before_markers("a\n", &["b"]), None);
+    }
+
+    #[test]
+    pub fn test_readme_link() {
+        let readme = "# Title\n\n## 2021\n\n- [Day 1: A](src/y2021/day01.rs)\n- [Day 3: C](src/y2021/day03.rs)\n\n## Running\n";
+
+        assert_eq!(
+            add_readme_link(readme, 2021, 2, Some("B")),
+            "# Title\n\n## 2021\n\n- [Day 1: A](src/y2021/day01.rs)\n- [Day 2: B](src/y2021/day02.rs)\n- [Day 3: C](src/y2021/day03.rs)\n\n## Running\n"
+        );
+        assert_eq!(
+            add_readme_link(readme, 2021, 4, None),
+            "# Title\n\n## 2021\n\n- [Day 1: A](src/y2021/day01.rs)\n- [Day 3: C](src/y2021/day03.rs)\n- [Day 4](src/y2021/day04.rs)\n\n## Running\n"
+        );
+        assert_eq!(
+            add_readme_link(readme, 2020, 1, None),
+            "# Title\n\n## 2020\n\n- [Day 1](src/y2020/day01.rs)\n\n## 2021\n\n- [Day 1: A](src/y2021/day01.rs)\n- [Day 3: C](src/y2021/day03.rs)\n\n## Running\n"
+        );
+        assert_eq!(
+            add_readme_link(readme, 2022, 1, None),
+            "# Title\n\n## 2021\n\n- [Day 1: A](src/y2021/day01.rs)\n- [Day 3: C](src/y2021/day03.rs)\n\n## 2022\n\n- [Day 1](src/y2022/day01.rs)\n\n## Running\n"
+        );
+    }
+
+    #[test]
+    pub fn test_answers_entry() {
+        let answers = add_answers_entry("[[answers]]\nday = 1\ninput = \"a.txt\"\n", 2022, 2);
+        let manifest = crate::answers::Manifest::parse(&answers, Path::new("")).unwrap();
+
+        assert_eq!(manifest.0.len(), 2);
+        assert_eq!((manifest.0[1].year, manifest.0[1].day), (2022, 2));
+        assert_eq!(manifest.0[1].input, input_path(2022, 2));
+        assert_eq!(manifest.0[1].part1, None);
+    }
+
+    // Removing day 25 and scaffolding it again should give back the same files
+    #[test]
+    pub fn test_scaffold() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root = std::env::temp_dir().join(format!("aoc2021-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2021")).unwrap();
+        for file in [
+            "src/template.rs",
+            "src/lib.rs",
+            "src/solution.rs",
+            "answers.toml",
+        ] {
+            fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+        for file in ["src/y2021/mod.rs", "README.md"] {
+            let text = fs::read_to_string(repo.join(file)).unwrap();
+            let text: String = text
+                .lines()
+                .filter(|l| !l.contains("day25"))
+                .map(|l| format!("{l}\n"))
+                .collect();
+            fs::write(root.join(file), text).unwrap();
+        }
+
+        let changed = scaffold(&root, 2021, 25, Some("Sea Cucumber")).unwrap();
+        assert_eq!(changed.len(), 4);
+        for file in ["src/y2021/mod.rs", "README.md"] {
+            assert_eq!(
+                fs::read_to_string(root.join(file)).unwrap(),
+                fs::read_to_string(repo.join(file)).unwrap(),
+                "{file}"
+            );
+        }
+
+        let module = fs::read_to_string(root.join("src/y2021/day25.rs")).unwrap();
+        assert!(module.contains("#[aoc_generator(day25)]"));
+        assert!(module.contains("const DAY: u8 = 25;"));
+        let answers = fs::read_to_string(root.join("answers.toml")).unwrap();
+        assert!(answers.ends_with("day = 25\ninput = \"input/2021/day25.txt\"\n"));
+
+        assert!(matches!(
+            scaffold(&root, 2021, 25, None),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert!(matches!(
+            scaffold(&root, 2021, 26, None),
+            Err(ScaffoldError::Day(26))
+        ));
+
+        // A new year gets its own module, registered next to 2021's
+        assert_eq!(scaffold(&root, 2022, 1, None).unwrap().len(), 6);
+        let module = fs::read_to_string(root.join("src/y2022/day01.rs")).unwrap();
+        assert!(!module.contains("aoc"));
+        let year = fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap();
+        assert!(year.contains("pub mod day01;\n// Insert before"));
+        assert!(year.contains("        entries::<day01::Solver>(),\n        // Insert before"));
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod y2021;\npub mod y2022;\n// Insert before"));
+        let solution = fs::read_to_string(root.join("src/solution.rs")).unwrap();
+        assert!(solution.contains("crate::y2022::registry(),\n        // Insert before"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+//! A small HTTP/1.1 API over the registry, for the `aoc2021-server` binary.
+//!
+//! `POST /{year}/day/{day}/part/{part}` with the puzzle input as the body answers with JSON, a
+//! `?variant=name` query picks a registered variant. A fixed pool of [`WORKERS`] threads answers
+//! the connections, each closed after one response.
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    str,
+    sync::{Arc, Mutex, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::{Value, json};
+
+use crate::solution::find;
+
+/// Bodies bigger than this are refused, real inputs are well under 100 KiB.
+pub const MAX_BODY: usize = 16 << 20;
+
+/// The request line and headers together are limited to this, clients send well under 1 KiB.
+pub const MAX_HEADERS: usize = 64 << 10;
+
+/// Connections answered at once. Solving is CPU bound, so more threads than cores only slow
+/// every request down.
+pub const WORKERS: usize = 8;
+
+// Accepted connections waiting for a worker, past this the listener stops accepting and new
+// connections wait in the kernel's backlog instead
+const QUEUE: usize = 64;
+
+// How long a connection can stall while sending the request or reading the response
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
+
+// Splits `/2021/day/1/part/2?variant=fastest` into the year, day, part and variant
+fn parse_target(target: &str) -> Option<(u16, u8, u8, Option<&str>)> {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let variant = query.split('&').find_map(|kv| kv.strip_prefix("variant="));
+
+    match path.split('/').collect::<Vec<_>>()[..] {
+        ["", year, "day", day, "part", part] => Some((
+            year.parse().ok()?,
+            day.parse().ok()?,
+            part.parse().ok()?,
+            variant,
+        )),
+        _ => None,
+    }
+}
+
+/// Answers one request, solver panics included.
+pub fn route(method: &str, target: &str, body: &[u8]) -> Response {
+    let Some((year, day, part, variant)) = parse_target(target) else {
+        return Response::error(404, "expected /{year}/day/{day}/part/{part}");
+    };
+    if method != "POST" {
+        return Response::error(405, "expected a POST with the input as the body");
+    }
+    let Some(entry) = find(year, day, part, variant) else {
+        return Response::error(404, format!("no solution for {year} day {day} part {part}"));
+    };
+    let Ok(input) = str::from_utf8(body) else {
+        return Response::error(400, "input is not valid UTF-8");
+    };
+    let input = input.trim_end_matches('\n');
+
+    let solve = || {
+        let start = Instant::now();
+        let runner = match entry.generate(input) {
+            Ok(runner) => runner,
+            Err(e) => {
+                return Response {
+                    status: 422,
+                    body: json!({
+                        "error": e.to_string(),
+                        "parse": {
+                            "line": e.line,
+                            "column": e.column,
+                            "text": e.text,
+                            "reason": e.reason,
+                        },
+                    }),
+                };
+            }
+        };
+
+        let inter = Instant::now();
+        let Some(answer) = runner.run() else {
+            return Response::error(422, "input has no answer");
+        };
+        let end = Instant::now();
+
+        Response {
+            status: 200,
+            body: json!({
+                "year": year,
+                "day": day,
+                "part": part,
+                "variant": variant,
+                "answer": answer,
+                "generator_ns": (inter - start).as_nanos() as u64,
+                "solve_ns": (end - inter).as_nanos() as u64,
+            }),
+        }
+    };
+
+    panic::catch_unwind(AssertUnwindSafe(solve))
+        .unwrap_or_else(|_| Response::error(500, "solver panicked"))
+}
+
+fn write_response(mut stream: impl Write, response: &Response) -> io::Result<()> {
+    let body = response.body.to_string();
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        reason(response.status),
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// Reads one request from `stream` and writes the response back.
+pub fn handle<S: Read + Write>(mut stream: S) -> io::Result<()> {
+    // Limited to the headers for now, the body gets its own limit once its length is known
+    let mut reader = BufReader::new((&mut stream).take(MAX_HEADERS as u64));
+
+    let mut request_line = String::new();
+    // Counted as it's consumed, the reader buffers past the headers so the limit left can't tell
+    let mut used = reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        drop(reader);
+        return write_response(stream, &Response::error(400, "malformed request line"));
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+
+    let mut length = Some(0);
+    let ended = loop {
+        let mut header = String::new();
+        let read = reader.read_line(&mut header)?;
+        used += read;
+        if read == 0 {
+            break false;
+        }
+        if header.trim_end().is_empty() {
+            break header.ends_with('\n');
+        }
+
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            length = parse_length(value.trim());
+        }
+    };
+
+    // Running out of the limit cuts the headers short rather than ending them
+    let response = if !ended && used >= MAX_HEADERS {
+        Response::error(431, format!("headers are limited to {MAX_HEADERS} bytes"))
+    } else if length.is_none() {
+        Response::error(400, "malformed Content-Length")
+    } else if let Some(length) = length.filter(|&length| length <= MAX_BODY) {
+        reader.get_mut().set_limit(length as u64);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        route(&method, &target, &body)
+    } else {
+        Response::error(413, format!("inputs are limited to {MAX_BODY} bytes"))
+    };
+
+    drop(reader);
+    write_response(stream, &response)
+}
+
+// Digits only, too many of them for a `usize` is still a length, just one over the limit
+fn parse_length(value: &str) -> Option<usize> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(value.parse().unwrap_or(usize::MAX))
+}
+
+/// Answers requests on `listener` with [`WORKERS`] threads, logging the connections it fails to accept or answer.
+pub fn serve(listener: TcpListener) {
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(QUEUE);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..WORKERS {
+        let receiver = Arc::clone(&receiver);
+        thread::spawn(move || {
+            loop {
+                // The guard is dropped before answering, so the other workers can take the next one
+                let Ok(stream) = receiver.lock().unwrap().recv() else {
+                    return;
+                };
+                let result = stream
+                    .set_read_timeout(Some(TIMEOUT))
+                    .and_then(|()| stream.set_write_timeout(Some(TIMEOUT)))
+                    .and_then(|()| handle(&stream));
+                if let Err(e) = result {
+                    eprintln!("connection failed: {e}");
+                }
+            }
+        });
+    }
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("unable to accept a connection: {e}");
+                // Errors like running out of file descriptors last a while, so don't spin on them
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+        };
+
+        // Blocks while every worker is busy and the queue is full
+        if sender.send(stream).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = b"199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    pub fn test_route() {
+        let res = route("POST", "/2021/day/1/part/2?variant=fastest", SAMPLE);
+        assert_eq!(res.status, 200);
+        assert_eq!(res.body["answer"], "5");
+        assert_eq!(res.body["variant"], "fastest");
+        assert!(res.body["solve_ns"].is_u64());
+
+        let res = route("POST", "/2021/day/1/part/1", b"199\nabc");
+        assert_eq!(res.status, 422);
+        assert_eq!(res.body["parse"]["line"], 2);
+        assert_eq!(res.body["parse"]["text"], "abc");
+
+        assert_eq!(route("GET", "/2021/day/1/part/1", b"").status, 405);
+        assert_eq!(route("POST", "/2021/day/26/part/1", SAMPLE).status, 404);
+        assert_eq!(route("POST", "/2021/day/1", SAMPLE).status, 404);
+        assert_eq!(route("POST", "/2021/day/1/part/1", b"\xff").status, 400);
+        assert_eq!(route("POST", "/2021/day/25/part/1", b">.").status, 422);
+    }
+
+    // A client that sends `request` all at once
+    struct Client {
+        request: io::Cursor<Vec<u8>>,
+        response: Vec<u8>,
+    }
+
+    impl Read for Client {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.request.read(buf)
+        }
+    }
+
+    impl Write for Client {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.response.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn status(request: Vec<u8>) -> String {
+        let mut client = Client {
+            request: io::Cursor::new(request),
+            response: Vec::new(),
+        };
+        handle(&mut client).unwrap();
+
+        let response = String::from_utf8(client.response).unwrap();
+        response.lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    pub fn test_handle_limits() {
+        let mut request = b"POST /2021/day/1/part/1 HTTP/1.1\r\n".to_vec();
+        request.extend(
+            b"Content-Length: 40\r\n\r\n199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n",
+        );
+        assert_eq!(status(request), "HTTP/1.1 200 OK");
+
+        let mut request = b"POST /2021/day/1/part/1 HTTP/1.1\r\nX-Padding: ".to_vec();
+        request.resize(MAX_HEADERS + 1, b'a');
+        assert_eq!(
+            status(request),
+            "HTTP/1.1 431 Request Header Fields Too Large"
+        );
+
+        let request = format!(
+            "POST /2021/day/1/part/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY + 1
+        );
+        assert_eq!(
+            status(request.into_bytes()),
+            "HTTP/1.1 413 Payload Too Large"
+        );
+        let request = "POST /2021/day/1/part/1 HTTP/1.1\r\nContent-Length: 4O\r\n\r\n";
+        assert_eq!(status(request.into()), "HTTP/1.1 400 Bad Request");
+        let request =
+            "POST /2021/day/1/part/1 HTTP/1.1\r\nContent-Length: 99999999999999999999\r\n\r\n";
+        assert_eq!(status(request.into()), "HTTP/1.1 413 Payload Too Large");
+
+        // Headers just under the limit, followed by a body the reader buffers past it
+        let body = "1\n".repeat(8 << 10);
+        let mut request = b"POST /2021/day/1/part/1 HTTP/1.1\r\nX-Padding: ".to_vec();
+        request.resize(MAX_HEADERS - 100, b'a');
+        request.extend(format!("\r\nContent-Length: {}\r\n\r\n{body}", body.len()).bytes());
+        assert_eq!(status(request), "HTTP/1.1 200 OK");
+    }
+
+    #[test]
+    pub fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /2021/day/1/part/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n",
+            SAMPLE.len()
+        )
+        .unwrap();
+        stream.write_all(SAMPLE).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\n"), "{head}");
+        let body: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["answer"], "7");
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+    str,
+};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::error::ParseError;
+
+/// A puzzle solution for a single day.
+///
+/// Every `yYYYY::dayNN` module implements this on its `Solver` type, so the
+/// days can be used generically instead of through the `aoc_runner_derive` macros.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// How many parts the puzzle has, day 25 only has one.
+    const PARTS: u8 = 2;
+
+    type Parsed: 'static;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Self::Answer1;
+
+    fn part2(parsed: &Self::Parsed) -> Self::Answer2;
+
+    /// Alternative implementations of the parts, registered under a name.
+    fn variants() -> Vec<Entry> {
+        Vec::new()
+    }
+}
+
+/// What a part returns.
+///
+/// Parts that can come up empty on input the puzzle promises never to give, like octopuses that
+/// never all flash at once, return an `Option` of their answer.
+pub trait Answer: 'static {
+    /// The answer as text, or `None` if there isn't one.
+    fn text(&self) -> Option<String>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn text(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answer!(i32, i64, isize, u32, u64, usize, String);
+
+impl<T: Answer> Answer for Option<T> {
+    fn text(&self) -> Option<String> {
+        self.as_ref().and_then(Answer::text)
+    }
+}
+
+/// The parsed input of an [`Entry`], ready to be solved.
+pub trait Runner {
+    /// Solves the part, `None` if the input has no answer.
+    fn run(&self) -> Option<String>;
+}
+
+struct Prepared<T, O> {
+    parsed: T,
+    solve: fn(&T) -> O,
+}
+
+impl<T, O: Answer> Runner for Prepared<T, O> {
+    fn run(&self) -> Option<String> {
+        (self.solve)(&self.parsed).text()
+    }
+}
+
+type Generator = Box<dyn Fn(&str) -> Result<Box<dyn Runner>, ParseError> + Send + Sync>;
+
+/// A single runnable year, day, part and variant.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    generator: Generator,
+}
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Entry")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .field("part", &self.part)
+            .field("variant", &self.variant)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Entry {
+    pub fn new<T: 'static, O: Answer>(
+        year: u16,
+        day: u8,
+        part: u8,
+        variant: Option<&'static str>,
+        parse: fn(&str) -> Result<T, ParseError>,
+        solve: fn(&T) -> O,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            variant,
+            generator: Box::new(move |input| {
+                let parsed = parse(input)?;
+                Ok(Box::new(Prepared { parsed, solve }))
+            }),
+        }
+    }
+
+    /// Runs the generator, returning the input ready to be solved.
+    pub fn generate(&self, input: &str) -> Result<Box<dyn Runner>, ParseError> {
+        (self.generator)(input)
+    }
+
+    /// Generates and solves the part, `None` if the input has no answer.
+    pub fn run(&self, input: &str) -> Result<Option<String>, ParseError> {
+        Ok(self.generate(input)?.run())
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} - Part {}", self.day, self.part)?;
+        if let Some(variant) = self.variant {
+            write!(f, " - {variant}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds the entries for both parts of `S` followed by its variants.
+pub fn entries<S: Solution>() -> Vec<Entry> {
+    let mut res = vec![Entry::new(S::YEAR, S::DAY, 1, None, S::parse, S::part1)];
+
+    if S::PARTS > 1 {
+        res.push(Entry::new(S::YEAR, S::DAY, 2, None, S::parse, S::part2));
+    }
+
+    res.extend(S::variants());
+    res
+}
+
+static REGISTRY: Lazy<Vec<Entry>> = Lazy::new(|| {
+    [
+        crate::y2021::registry(),
+        // Insert before
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+});
+
+/// Every registered year, day, part and variant, ordered by year then day.
+pub fn registry() -> &'static [Entry] {
+    &REGISTRY
+}
+
+/// The years with at least one registered day, in order.
+pub fn years() -> Vec<u16> {
+    registry().iter().map(|e| e.year).dedup().collect()
+}
+
+/// Where the puzzle input for `day` of `year` is kept, relative to the repository root.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{year}/day{day}.txt"))
+}
+
+/// Finds the entry for `day` and `part` of `year`, `variant` of `None` being the default
+/// implementation.
+pub fn find(year: u16, day: u8, part: u8, variant: Option<&str>) -> Option<&'static Entry> {
+    registry()
+        .iter()
+        .find(|e| (e.year, e.day, e.part, e.variant) == (year, day, part, variant))
+}
+
+/// The reasons [`cross_check`] can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckError {
+    /// There is nothing registered for the year and day.
+    UnknownDay(u16, u8),
+    /// One of the generators rejected the input.
+    Parse(ParseError),
+    /// A part found no answer for the input.
+    NoAnswer {
+        day: u8,
+        part: u8,
+        variant: Option<&'static str>,
+    },
+    /// The variants of a part disagree, every answer is listed with its variant.
+    Mismatch {
+        day: u8,
+        part: u8,
+        answers: Vec<(Option<&'static str>, String)>,
+    },
+}
+
+impl Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckError::UnknownDay(year, day) => write!(f, "no solution for {year} day {day}"),
+            CheckError::Parse(e) => write!(f, "{e}"),
+            CheckError::NoAnswer { day, part, variant } => {
+                write!(f, "Day {day} - Part {part}")?;
+                if let Some(variant) = variant {
+                    write!(f, " - {variant}")?;
+                }
+
+                write!(f, ": no answer")
+            }
+            CheckError::Mismatch { day, part, answers } => {
+                write!(f, "Day {day} - Part {part}: variants disagree")?;
+                for (variant, answer) in answers {
+                    write!(f, "\n\t{}: {answer}", variant.unwrap_or("default"))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckError {}
+
+impl From<ParseError> for CheckError {
+    fn from(e: ParseError) -> Self {
+        CheckError::Parse(e)
+    }
+}
+
+/// Runs every registered variant of `day` of `year` on `input`, failing if any part's answers
+/// disagree.
+///
+/// On success every entry is returned with its answer.
+pub fn cross_check(
+    year: u16,
+    day: u8,
+    input: &str,
+) -> Result<Vec<(&'static Entry, String)>, CheckError> {
+    let results = registry()
+        .iter()
+        .filter(|e| (e.year, e.day) == (year, day))
+        .map(|e| match e.run(input)? {
+            Some(answer) => Ok((e, answer)),
+            None => Err(CheckError::NoAnswer {
+                day,
+                part: e.part,
+                variant: e.variant,
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if results.is_empty() {
+        return Err(CheckError::UnknownDay(year, day));
+    }
+
+    for part in 1..=2 {
+        let answers: Vec<_> = results
+            .iter()
+            .filter(|(e, _)| e.part == part)
+            .map(|(e, answer)| (e.variant, answer.clone()))
+            .collect();
+
+        if answers.iter().any(|(_, a)| *a != answers[0].1) {
+            return Err(CheckError::Mismatch { day, part, answers });
+        }
+    }
+
+    Ok(results)
+}
+
+/// Feeds `data` through every registered generator and part of `day` of `year`, for the fuzz
+/// targets in `fuzz/`.
+///
+/// Rejected input and parts without an answer are fine, only a panic is a bug.
+pub fn fuzz(year: u16, day: u8, data: &[u8]) {
+    let Ok(input) = str::from_utf8(data) else {
+        return;
+    };
+
+    for entry in registry().iter().filter(|e| (e.year, e.day) == (year, day)) {
+        if let Ok(runner) = entry.generate(input) {
+            runner.run();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_registry() {
+        let days: Vec<_> = registry().iter().map(|e| (e.year, e.day)).collect();
+        assert!(days.is_sorted());
+        assert_eq!(days.first(), Some(&(2021, 1)));
+        assert_eq!(days.last(), Some(&(2021, 25)));
+
+        assert_eq!(
+            registry().iter().filter(|e| e.variant.is_none()).count(),
+            49
+        );
+        assert!(find(2021, 25, 2, None).is_none());
+        assert!(find(2020, 1, 1, None).is_none());
+        assert_eq!(years(), [2021]);
+        assert_eq!(input_path(2021, 7), PathBuf::from("input/2021/day7.txt"));
+        assert!(find(2021, 1, 2, Some("fastest")).is_some());
+    }
+
+    #[test]
+    pub fn test_run() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+        assert_eq!(
+            find(2021, 1, 1, None).unwrap().run(input),
+            Ok(Some("7".to_string()))
+        );
+        assert_eq!(
+            find(2021, 1, 2, Some("fastest")).unwrap().run(input),
+            Ok(Some("5".to_string()))
+        );
+        assert_eq!(
+            find(2021, 1, 1, None)
+                .unwrap()
+                .run("1\nx")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert_eq!(
+            find(2021, 1, 2, None).unwrap().to_string(),
+            "Day 1 - Part 2".to_string()
+        );
+    }
+
+    #[test]
+    pub fn test_cross_check() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+        assert_eq!(cross_check(2021, 1, input).unwrap().len(), 6);
+        assert_eq!(
+            cross_check(2021, 26, input).unwrap_err(),
+            CheckError::UnknownDay(2021, 26)
+        );
+        assert!(matches!(
+            cross_check(2021, 1, "1\nx"),
+            Err(CheckError::Parse(_))
+        ));
+    }
+
+    // A few inputs that used to panic somewhere, the fuzz targets in `fuzz/` search much further
+    #[test]
+    pub fn test_fuzz() {
+        let inputs = [
+            "",
+            "\n",
+            "\n\n",
+            "0",
+            "-1",
+            "99999999999999999999",
+            "18446744073709551615\n18446744073709551615",
+            "[[[[[[1,1],1],1],1],1],1]",
+            ",",
+            "é",
+            "target area: x=-1..1, y=-1..1",
+            "fold along x=0",
+            "inp w",
+        ];
+
+        let mut days: Vec<_> = registry().iter().map(|e| (e.year, e.day)).collect();
+        days.dedup();
+
+        for (year, day) in days {
+            for input in inputs {
+                fuzz(year, day, input.as_bytes());
+            }
+            fuzz(year, day, &[0xff, 0xfe]);
+
+            // Cutting a valid input short leaves it half finished
+            let Some(input) = crate::r#gen::find(year, day).map(|g| g.generate(0, 3)) else {
+                continue;
+            };
+            for len in (0..input.len()).step_by(input.len() / 7 + 1) {
+                if input.is_char_boundary(len) {
+                    fuzz(year, day, &input.as_bytes()[..len]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Line-by-line input for the days of 2021 that can be solved without holding the whole input in
+//! memory.
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Answer,
+    y2021::{self, day01, day02, day05, day10},
+};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// The input is read, but its answer doesn't fit the answer's type.
+    Overflow,
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "unable to read input: {e}"),
+            StreamError::Parse(e) => write!(f, "{e}"),
+            StreamError::Overflow => write!(f, "the answer goes out of range"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// Calls `f` with every line of `reader`, without the line ending, reusing one buffer throughout.
+///
+/// Each line gets its own [`Source`], errors are reported with their line number in the whole stream.
+pub fn for_each_line<R: BufRead>(
+    day: u8,
+    mut reader: R,
+    mut f: impl FnMut(&Source, &str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    let mut buf = String::new();
+
+    for number in 1.. {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+
+        let line = buf.trim_end_matches(['\n', '\r']);
+        f(&Source::new(day, line), line).map_err(|mut e| {
+            if e.line > 0 {
+                e.line += number - 1;
+            }
+
+            e
+        })?;
+    }
+
+    Ok(())
+}
+
+/// The answers to both parts as text, `None` for a part with no answer.
+pub type Answers = (Option<String>, Option<String>);
+
+/// Runs the streaming version of `day` of `year` on `reader`, or `None` if it doesn't have one.
+pub fn solve<R: BufRead>(year: u16, day: u8, reader: R) -> Option<Result<Answers, StreamError>> {
+    fn show<A: Answer, B: Answer>((a, b): (A, B)) -> Answers {
+        (a.text(), b.text())
+    }
+
+    if year != y2021::YEAR {
+        return None;
+    }
+
+    Some(match day {
+        1 => day01::stream(reader).map(show),
+        2 => day02::stream(reader).map(show),
+        5 => day05::stream(reader).map(show),
+        10 => day10::stream(reader).map(show),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_lines() {
+        let mut lines = Vec::new();
+        for_each_line(0, "a\r\nb\n\nc".as_bytes(), |_, l| {
+            lines.push(l.to_string());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(lines, ["a", "b", "", "c"]);
+    }
+
+    #[test]
+    pub fn test_error_line() {
+        let err = for_each_line(1, "1\n2\nx3\n".as_bytes(), |src, l| {
+            src.parse::<u32>(l.trim_start_matches('x')).map(drop)?;
+            src.parse::<u32>(l).map(drop)
+        })
+        .unwrap_err();
+
+        let StreamError::Parse(err) = err else {
+            panic!("expected a parse error, got {err}");
+        };
+        assert_eq!((err.day, err.line, err.column), (1, 3, 1));
+    }
+
+    #[test]
+    pub fn test_solve() {
+        let answers = solve(2021, 2, "forward 5\ndown 5".as_bytes())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(answers, (Some("25".to_string()), Some("0".to_string())));
+        let answers = solve(2021, 10, "(]".as_bytes()).unwrap().unwrap();
+        assert_eq!(answers, (Some("57".to_string()), None));
+        assert!(solve(2021, 3, "".as_bytes()).is_none());
+        assert!(solve(2022, 1, "".as_bytes()).is_none());
+    }
+
+    #[test]
+    pub fn test_io_error() {
+        let err = for_each_line(1, [0xff, b'\n'].as_slice(), |_, _| Ok(())).unwrap_err();
+
+        assert!(matches!(err, StreamError::Io(_)));
+    }
+}
@@ -1,10 +1,16 @@
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
 #[derive(Debug, PartialEq)]
 pub struct Object {}
 
 #[aoc_generator(dayN)]
-pub fn generator(input: &str) -> Object {
+pub fn generator(input: &str) -> Result<Object, ParseError> {
+    let src = Source::new(N, input);
     unimplemented!()
 }
 
@@ -18,6 +24,29 @@ pub fn part2(inputs: &Object) -> usize {
     unimplemented!()
 }
 
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = N;
+
+    type Parsed = Object;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,34 +55,19 @@ mod tests {
 
     #[test]
     pub fn test_input() {
-        println!("{:?}", generator(SAMPLE));
+        println!("{:?}", generator(SAMPLE).unwrap());
 
         // assert_eq!(generator(SAMPLE), Object());
     }
 
     #[test]
     pub fn test1() {
-        // assert_eq!(part1(&generator(SAMPLE)), 7);
+        // assert_eq!(part1(&generator(SAMPLE).unwrap()), 7);
     }
 
     #[test]
     pub fn test2() {
-        // assert_eq!(part2(&generator(SAMPLE)), 336);
+        // assert_eq!(part2(&generator(SAMPLE).unwrap()), 336);
     }
 
-    mod regression {
-        use super::*;
-
-        const INPUT: &str = include_str!("../input/2021/dayN.txt");
-        const ANSWERS: (usize, usize) = (0, 0);
-
-        #[test]
-        pub fn test() {
-            let input = INPUT.trim_end_matches('\n');
-            let output = generator(input);
-
-            assert_eq!(part1(&output), ANSWERS.0);
-            assert_eq!(part2(&output), ANSWERS.1);
-        }
-    }
 }
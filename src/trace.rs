@@ -0,0 +1,203 @@
+//! Opt-in step-by-step traces of what a solver did, for when an answer isn't the one expected.
+//!
+//! The traced days have `partN_traced` functions taking a [`Tracer`], their plain `partN` pass
+//! [`NoTrace`], which never builds the events at all.
+
+use std::fmt;
+
+use crate::{
+    error::ParseError,
+    solution::Answer,
+    y2021::{self, day04, day13, day18, day19, day23},
+};
+
+/// Something a solver did, see the day's puzzle for what each means.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// Day 4: `number` was called.
+    Draw { number: usize },
+    /// Day 4: `board`, counting from 0, won with `score`.
+    Win { board: usize, score: usize },
+    /// Day 13: the paper was folded along `axis` = `at`, leaving `dots` visible.
+    Fold { axis: char, at: usize, dots: usize },
+    /// Day 18: the pair `[left,right]` exploded.
+    Explode { left: usize, right: usize },
+    /// Day 18: the regular number `value` split in two.
+    Split { value: usize },
+    /// Day 19: `scanner` was matched at `position` relative to scanner 0, `beacons` are now known.
+    Merge {
+        scanner: usize,
+        position: (i32, i32, i32),
+        beacons: usize,
+    },
+    /// Day 23: a move using `energy`, `total` so far, leaving the burrow as `map`.
+    Move {
+        energy: usize,
+        total: usize,
+        map: String,
+    },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Draw { number } => write!(f, "draw {number}"),
+            Event::Win { board, score } => write!(f, "board {board} wins with score {score}"),
+            Event::Fold { axis, at, dots } => write!(f, "fold along {axis}={at}: {dots} dots"),
+            Event::Explode { left, right } => write!(f, "explode [{left},{right}]"),
+            Event::Split { value } => write!(f, "split {value}"),
+            Event::Merge {
+                scanner,
+                position: (x, y, z),
+                beacons,
+            } => write!(f, "scanner {scanner} at {x},{y},{z}: {beacons} beacons"),
+            Event::Move { energy, total, map } => {
+                write!(
+                    f,
+                    "move using {energy} energy, {total} total\n{}",
+                    map.trim_end()
+                )
+            }
+        }
+    }
+}
+
+/// Receives the events of a traced solver.
+pub trait Tracer {
+    /// Records the event built by `event`, which is only called if the tracer wants it.
+    fn trace(&mut self, event: impl FnOnce() -> Event);
+}
+
+/// Ignores every event.
+pub struct NoTrace;
+
+impl Tracer for NoTrace {
+    #[inline]
+    fn trace(&mut self, _: impl FnOnce() -> Event) {}
+}
+
+/// Keeps every event in order.
+impl Tracer for Vec<Event> {
+    fn trace(&mut self, event: impl FnOnce() -> Event) {
+        self.push(event());
+    }
+}
+
+/// One event per line.
+pub fn render(events: &[Event]) -> String {
+    events.iter().map(|e| format!("{e}\n")).collect()
+}
+
+/// The answer to a part, if there is one, and the events on the way to it.
+pub type Explanation = (Option<String>, Vec<Event>);
+
+/// Runs `part` of the traced `day` of `year` on `input`, or returns `None` if it isn't traced.
+pub fn explain(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+) -> Option<Result<Explanation, ParseError>> {
+    type Explain = fn(&str, bool, &mut Vec<Event>) -> Result<Option<String>, ParseError>;
+
+    if year != y2021::YEAR || !(1..=2).contains(&part) {
+        return None;
+    }
+
+    let explain: Explain = match day {
+        4 => |input, first, t| {
+            let bingo = day04::generator(input)?;
+            Ok(if first {
+                day04::part1_traced(&bingo, t)
+            } else {
+                day04::part2_traced(&bingo, t)
+            }
+            .text())
+        },
+        13 => |input, first, t| {
+            let paper = day13::generator(input)?;
+            Ok(if first {
+                day13::part1_traced(&paper, t).text()
+            } else {
+                day13::part2_traced(&paper, t).text()
+            })
+        },
+        18 => |input, first, t| {
+            let numbers = day18::generator(input)?;
+            Ok(if first {
+                day18::part1_traced(&numbers, t)
+            } else {
+                day18::part2_traced(&numbers, t)
+            }
+            .text())
+        },
+        19 => |input, first, t| {
+            let scanners = day19::generator(input)?;
+            Ok(if first {
+                day19::part1_traced(&scanners, t).text()
+            } else {
+                day19::part2_traced(&scanners, t).text()
+            })
+        },
+        23 => |input, first, t| {
+            Ok(if first {
+                day23::part1_traced(&day23::generator1(input)?, t)
+            } else {
+                day23::part2_traced(&day23::generator2(input)?, t)
+            }
+            .text())
+        },
+        _ => return None,
+    };
+
+    let mut events = Vec::new();
+    Some(explain(input, part == 1, &mut events).map(|answer| (answer, events)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_render() {
+        let events = [
+            Event::Draw { number: 7 },
+            Event::Fold {
+                axis: 'y',
+                at: 7,
+                dots: 17,
+            },
+            Event::Merge {
+                scanner: 1,
+                position: (68, -1246, -43),
+                beacons: 38,
+            },
+        ];
+
+        assert_eq!(
+            render(&events),
+            "draw 7\nfold along y=7: 17 dots\nscanner 1 at 68,-1246,-43: 38 beacons\n"
+        );
+    }
+
+    #[test]
+    pub fn test_explain() {
+        let (answer, events) = explain(2021, 13, 1, "6,10\n6,4\n\nfold along y=7")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(answer.as_deref(), Some("1"));
+        assert_eq!(
+            events,
+            [Event::Fold {
+                axis: 'y',
+                at: 7,
+                dots: 1
+            }]
+        );
+        assert!(explain(2021, 1, 1, "").is_none());
+        assert!(explain(2021, 4, 3, "").is_none());
+        assert!(explain(2021, 4, 1, "x").unwrap().is_err());
+    }
+}
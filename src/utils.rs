@@ -0,0 +1,230 @@
+//! Small parsing and grid helpers shared between days.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use num::PrimInt;
+
+/// Why [`try_parse_pair`] or [`try_parse_range`] failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairError<E> {
+    /// The separator (`,` or `..`) wasn't found.
+    MissingSeparator(&'static str),
+    First(E),
+    Second(E),
+}
+
+impl<E: fmt::Display> fmt::Display for PairError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PairError::MissingSeparator(sep) => write!(f, "expected `{sep}`"),
+            PairError::First(e) => write!(f, "first value: {e}"),
+            PairError::Second(e) => write!(f, "second value: {e}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for PairError<E> {}
+
+fn try_parse_split<T: PrimInt + FromStr>(
+    s: &str,
+    sep: &'static str,
+) -> Result<(T, T), PairError<T::Err>> {
+    let (a, b) = s.split_once(sep).ok_or(PairError::MissingSeparator(sep))?;
+
+    Ok((
+        a.trim().parse().map_err(PairError::First)?,
+        b.trim().parse().map_err(PairError::Second)?,
+    ))
+}
+
+/// Parses `a,b` into a pair of integers, whitespace around either number is ignored.
+pub fn try_parse_pair<T: PrimInt + FromStr>(s: &str) -> Result<(T, T), PairError<T::Err>> {
+    try_parse_split(s, ",")
+}
+
+/// Parses `min..max` into a pair of integers, whitespace around either number is ignored.
+pub fn try_parse_range<T: PrimInt + FromStr>(s: &str) -> Result<(T, T), PairError<T::Err>> {
+    try_parse_split(s, "..")
+}
+
+/// [`try_parse_pair`] without the reason it failed.
+pub fn parse_pair<T: PrimInt + FromStr>(s: &str) -> Option<(T, T)> {
+    try_parse_pair(s).ok()
+}
+
+/// [`try_parse_range`] without the reason it failed.
+pub fn parse_range<T: PrimInt + FromStr>(s: &str) -> Option<(T, T)> {
+    try_parse_range(s).ok()
+}
+
+/// Collects an iterator into an array, slots past the end of the iterator are left as `T::default()`
+/// and extra items are dropped.
+pub fn build_array<T: Default + Copy, const N: usize>(it: impl IntoIterator<Item = T>) -> [T; N] {
+    let mut res = [T::default(); N];
+    for (slot, x) in res.iter_mut().zip(it) {
+        *slot = x;
+    }
+
+    res
+}
+
+/// Collects an iterator into an array, or `None` unless it has exactly `N` items.
+pub fn try_build_array<T: Default + Copy, const N: usize>(
+    it: impl IntoIterator<Item = T>,
+) -> Option<[T; N]> {
+    let mut res = [T::default(); N];
+    let mut it = it.into_iter();
+    for slot in res.iter_mut() {
+        *slot = it.next()?;
+    }
+
+    it.next().is_none().then_some(res)
+}
+
+// Offsets by -1, 0 or 1, staying within 0..max
+fn step<T: PrimInt>(n: T, d: i8, max: T) -> Option<T> {
+    let n = match d {
+        -1 => n.checked_sub(&T::one())?,
+        1 => n.checked_add(&T::one())?,
+        _ => n,
+    };
+
+    (n >= T::zero() && n < max).then_some(n)
+}
+
+fn offsets<T: PrimInt>(
+    r: T,
+    c: T,
+    r_max: T,
+    c_max: T,
+    deltas: &'static [(i8, i8)],
+) -> impl Iterator<Item = (T, T)> {
+    deltas
+        .iter()
+        .filter_map(move |&(dr, dc)| Some((step(r, dr, r_max)?, step(c, dc, c_max)?)))
+}
+
+/// The up to 4 orthogonal neighbours of `(r, c)` in a `r_max` by `c_max` grid, in the order up, down,
+/// left, right.
+pub fn neighbors<T: PrimInt>(r: T, c: T, r_max: T, c_max: T) -> impl Iterator<Item = (T, T)> {
+    offsets(r, c, r_max, c_max, &[(-1, 0), (1, 0), (0, -1), (0, 1)])
+}
+
+/// The up to 8 neighbours of `(r, c)` including diagonals, in row-major order.
+pub fn neighbors_diag<T: PrimInt>(r: T, c: T, r_max: T, c_max: T) -> impl Iterator<Item = (T, T)> {
+    offsets(
+        r,
+        c,
+        r_max,
+        c_max,
+        &[
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ],
+    )
+}
+
+/// Serde support for arrays of any length, including const generic ones, which serde itself only
+/// handles up to 32 items. Use it with `#[serde(with = "crate::utils::serde_array")]`.
+#[cfg(feature = "serde")]
+pub mod serde_array {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    pub fn serialize<S, T, const N: usize>(array: &[T; N], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        s.collect_seq(array)
+    }
+
+    pub fn deserialize<'de, D, T, const N: usize>(d: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let items = Vec::<T>::deserialize(d)?;
+        let len = items.len();
+
+        items
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{N} items").as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::IntErrorKind;
+
+    #[test]
+    pub fn test_parse() {
+        assert_eq!(parse_pair::<u16>("0,9"), Some((0, 9)));
+        assert_eq!(parse_pair::<i64>(" -3 , 12 "), Some((-3, 12)));
+        assert_eq!(parse_range::<i32>("-10..-5"), Some((-10, -5)));
+        assert_eq!(parse_range::<u8>("1..256"), None);
+        assert_eq!(parse_pair::<u8>("1..2"), None);
+    }
+
+    #[test]
+    pub fn test_try_parse() {
+        assert_eq!(
+            try_parse_pair::<u32>("1 2"),
+            Err(PairError::MissingSeparator(","))
+        );
+        assert!(matches!(
+            try_parse_range::<u32>("x..2"),
+            Err(PairError::First(_))
+        ));
+        assert!(matches!(
+            try_parse_range::<u8>("1..300"),
+            Err(PairError::Second(e)) if *e.kind() == IntErrorKind::PosOverflow
+        ));
+        assert_eq!(
+            try_parse_pair::<u8>("1").unwrap_err().to_string(),
+            "expected `,`"
+        );
+    }
+
+    #[test]
+    pub fn test_build_array() {
+        assert_eq!(build_array::<u8, 3>([1, 2]), [1, 2, 0]);
+        assert_eq!(build_array::<u8, 2>([1, 2, 3]), [1, 2]);
+        assert_eq!(try_build_array::<u8, 2>([1, 2]), Some([1, 2]));
+        assert_eq!(try_build_array::<u8, 2>([1]), None);
+        assert_eq!(try_build_array::<u8, 2>([1, 2, 3]), None);
+    }
+
+    #[test]
+    pub fn test_neighbors() {
+        assert_eq!(
+            neighbors(0usize, 0, 3, 3).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            neighbors(1u8, 1, 3, 3).collect::<Vec<_>>(),
+            [(0, 1), (2, 1), (1, 0), (1, 2)]
+        );
+        assert_eq!(neighbors(2i32, 2, 3, 3).count(), 2);
+        assert_eq!(
+            neighbors(u8::MAX, 0, u8::MAX, 1).collect::<Vec<_>>(),
+            [(254, 0)]
+        );
+    }
+
+    #[test]
+    pub fn test_neighbors_diag() {
+        assert_eq!(
+            neighbors_diag(0usize, 0, 2, 2).collect::<Vec<_>>(),
+            [(0, 1), (1, 0), (1, 1)]
+        );
+        assert_eq!(neighbors_diag(1i16, 1, 3, 3).count(), 8);
+        assert_eq!(neighbors_diag(0u32, 4, 1, 5).collect::<Vec<_>>(), [(0, 3)]);
+    }
+}
@@ -0,0 +1,661 @@
+use std::{cmp::Ordering, collections::VecDeque, io::BufRead};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::{Entry, Solution},
+    stream::{StreamError, for_each_line},
+};
+
+#[aoc_generator(day1)]
+pub fn generator(input: &str) -> Result<Vec<usize>, ParseError> {
+    let src = Source::new(1, input);
+    input.lines().map(|s| src.parse(s)).collect()
+}
+
+#[aoc(day1, part1)]
+pub fn part1(inputs: &[usize]) -> usize {
+    inputs.windows(2).filter(|w| w[0] < w[1]).count()
+}
+
+#[aoc(day1, part2)]
+pub fn part2(inputs: &[usize]) -> usize {
+    // The middle two depths are in both sums, comparing the ends can't overflow
+    inputs.windows(4).filter(|w| w[0] < w[3]).count()
+}
+
+fn solve_fastest<const N: usize>(inputs: &[usize]) -> usize {
+    let mut count = 0;
+
+    for i in N..inputs.len() {
+        if inputs[i - N] < inputs[i] {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+#[aoc(day1, part1, fastest)]
+pub fn part1_fastest(inputs: &[usize]) -> usize {
+    solve_fastest::<1>(inputs)
+}
+
+#[aoc(day1, part2, fastest)]
+pub fn part2_fastest(inputs: &[usize]) -> usize {
+    solve_fastest::<3>(inputs)
+}
+
+/// Depths packed as `u32`s when they all fit, which halves the memory of a long series and doubles
+/// the comparisons a vector register holds.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Depths {
+    Narrow(Vec<u32>),
+    /// The fallback for series with a depth past `u32::MAX`.
+    Wide(Vec<usize>),
+}
+
+#[aoc_generator(day1, part1, vectorized)]
+#[aoc_generator(day1, part2, vectorized)]
+pub fn generator_vectorized(input: &str) -> Result<Depths, ParseError> {
+    let mut narrow = Vec::new();
+
+    for s in input.lines() {
+        let Ok(depth) = s.parse() else {
+            // Too deep for a `u32`, or not a depth at all, which the wide parse reports
+            drop(narrow);
+            return generator(input).map(Depths::Wide);
+        };
+        narrow.push(depth);
+    }
+
+    Ok(Depths::Narrow(narrow))
+}
+
+// Each block's count fits in a `u32`, which keeps the sum in the lanes of one vector register
+const BLOCK: usize = 1 << 16;
+
+/// Counts the depths deeper than the one `offset` readings before, what [`part1_fastest`] and
+/// [`part2_fastest`] do for offsets of 1 and 3, in a loop the compiler turns into SIMD compares.
+pub fn count_increases(depths: &[u32], offset: usize) -> usize {
+    let Some(len) = depths.len().checked_sub(offset) else {
+        return 0;
+    };
+    let (before, after) = (&depths[..len], &depths[offset..]);
+
+    before
+        .chunks(BLOCK)
+        .zip(after.chunks(BLOCK))
+        .map(|(a, b)| a.iter().zip(b).map(|(x, y)| u32::from(x < y)).sum::<u32>() as usize)
+        .sum()
+}
+
+#[aoc(day1, part1, vectorized)]
+pub fn part1_vectorized(depths: &Depths) -> usize {
+    match depths {
+        Depths::Narrow(depths) => count_increases(depths, 1),
+        Depths::Wide(depths) => solve_fastest::<1>(depths),
+    }
+}
+
+#[aoc(day1, part2, vectorized)]
+pub fn part2_vectorized(depths: &Depths) -> usize {
+    match depths {
+        Depths::Narrow(depths) => count_increases(depths, 3),
+        Depths::Wide(depths) => solve_fastest::<3>(depths),
+    }
+}
+
+/// How the sums of every `size` consecutive depths change along a series, see [`analyze_windows`].
+#[derive(Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Windows {
+    /// Windows with a bigger sum than the one before, part 1 for windows of 1 and part 2 for 3.
+    pub increases: usize,
+    /// Windows with a smaller sum than the one before.
+    pub decreases: usize,
+    /// Windows with the same sum as the one before.
+    pub flat: usize,
+    /// The most increases in a row.
+    pub longest_increase: usize,
+    /// Every window in order, `size - 1` fewer than there are depths.
+    pub rolling: Vec<Rolling>,
+}
+
+/// The depths in one window.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rolling {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+}
+
+/// Slides a window of `size` depths along `depths`, or `None` for an empty window.
+///
+/// Runs in linear time whatever the size: the sums are compared by their ends, and the minimum and
+/// maximum come off queues of the depths that can still be one.
+pub fn analyze_windows(depths: &[usize], size: usize) -> Option<Windows> {
+    if size == 0 {
+        return None;
+    }
+    let mut stats = Windows::default();
+
+    // Neighbouring windows share all but their ends, so comparing those compares the sums
+    let mut run = 0;
+    for (a, b) in depths.iter().zip(&depths[size.min(depths.len())..]) {
+        match a.cmp(b) {
+            Ordering::Less => stats.increases += 1,
+            Ordering::Greater => stats.decreases += 1,
+            Ordering::Equal => stats.flat += 1,
+        }
+
+        run = if a < b { run + 1 } else { 0 };
+        stats.longest_increase = stats.longest_increase.max(run);
+    }
+
+    // Indexes of depths with nothing smaller (or bigger) after them in the window, the front is
+    // the window's minimum (or maximum)
+    let (mut mins, mut maxs) = (VecDeque::new(), VecDeque::new());
+    let mut sum = 0_u128;
+    for (i, &depth) in depths.iter().enumerate() {
+        while mins.back().is_some_and(|&j| depths[j] >= depth) {
+            mins.pop_back();
+        }
+        mins.push_back(i);
+        while maxs.back().is_some_and(|&j| depths[j] <= depth) {
+            maxs.pop_back();
+        }
+        maxs.push_back(i);
+        sum += depth as u128;
+
+        let Some(start) = (i + 1).checked_sub(size) else {
+            continue;
+        };
+        for queue in [&mut mins, &mut maxs] {
+            if queue.front() < Some(&start) {
+                queue.pop_front();
+            }
+        }
+
+        stats.rolling.push(Rolling {
+            min: depths[mins[0]],
+            max: depths[maxs[0]],
+            mean: sum as f64 / size as f64,
+        });
+        sum -= depths[start] as u128;
+    }
+
+    Some(stats)
+}
+
+/// What makes a reading stand out, see [`find_anomalies`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Thresholds {
+    /// The biggest change from one depth to the next that isn't a jump.
+    pub jump: usize,
+    /// How many readings in a row of the same depth make a plateau, at least 2.
+    pub plateau: usize,
+    /// How many of the readings before a depth its median is taken over, at least 1.
+    pub median_window: usize,
+    /// The furthest a depth can be from that median without being an outlier.
+    pub outlier: usize,
+}
+
+/// A suspicious stretch of a sonar sweep, found by [`find_anomalies`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anomaly {
+    /// `depths[index]` is more than the jump threshold away from the depth before it.
+    Jump {
+        index: usize,
+        from: usize,
+        to: usize,
+    },
+    /// `len` readings from `depths[start]` on are all the same depth.
+    Plateau { start: usize, len: usize },
+    /// `depths[index]` is more than the outlier threshold away from the median of the readings
+    /// before it.
+    Outlier { index: usize, median: usize },
+}
+
+impl Anomaly {
+    /// Where the anomaly starts in the series.
+    pub fn index(&self) -> usize {
+        match *self {
+            Anomaly::Jump { index, .. } | Anomaly::Outlier { index, .. } => index,
+            Anomaly::Plateau { start, .. } => start,
+        }
+    }
+}
+
+/// Flags the jumps, plateaus and outliers in `depths` in the order they start, or `None` if the
+/// thresholds ask for plateaus shorter than 2 or an empty median window.
+///
+/// Readings without `median_window` readings before them have no median and are never outliers,
+/// an even window takes the lower of its middle two.
+pub fn find_anomalies(depths: &[usize], thresholds: &Thresholds) -> Option<Vec<Anomaly>> {
+    if thresholds.plateau < 2 || thresholds.median_window == 0 {
+        return None;
+    }
+    let mut anomalies = Vec::new();
+
+    for (i, w) in depths.windows(2).enumerate() {
+        if w[0].abs_diff(w[1]) > thresholds.jump {
+            anomalies.push(Anomaly::Jump {
+                index: i + 1,
+                from: w[0],
+                to: w[1],
+            });
+        }
+    }
+
+    let mut start = 0;
+    for i in 1..=depths.len() {
+        if i == depths.len() || depths[i] != depths[start] {
+            if i - start >= thresholds.plateau {
+                anomalies.push(Anomaly::Plateau {
+                    start,
+                    len: i - start,
+                });
+            }
+            start = i;
+        }
+    }
+
+    // The window kept sorted, so the median is always in the middle
+    let size = thresholds.median_window;
+    let mut sorted: Vec<usize> = Vec::with_capacity(size);
+    for (i, &depth) in depths.iter().enumerate() {
+        if i >= size {
+            let median = sorted[(size - 1) / 2];
+            if depth.abs_diff(median) > thresholds.outlier {
+                anomalies.push(Anomaly::Outlier { index: i, median });
+            }
+
+            let old = sorted
+                .binary_search(&depths[i - size])
+                .unwrap_or_else(|i| i);
+            sorted.remove(old);
+        }
+
+        let new = sorted.binary_search(&depth).unwrap_or_else(|i| i);
+        sorted.insert(new, depth);
+    }
+
+    anomalies.sort_by_key(Anomaly::index);
+    Some(anomalies)
+}
+
+/// Counts both parts' depth increases while reading, keeping only the last 3 depths.
+pub fn stream<R: BufRead>(reader: R) -> Result<(usize, usize), StreamError> {
+    let mut window = [0; 3];
+    let (mut seen, mut part1, mut part2) = (0, 0, 0);
+
+    for_each_line(1, reader, |src, line| {
+        let depth: usize = src.parse(line)?;
+
+        // `window[seen % 3]` is the depth 3 readings back, which the next window drops
+        if seen >= 1 && window[(seen - 1) % 3] < depth {
+            part1 += 1;
+        }
+        if seen >= 3 && window[seen % 3] < depth {
+            part2 += 1;
+        }
+
+        window[seen % 3] = depth;
+        seen += 1;
+
+        Ok(())
+    })?;
+
+    Ok((part1, part2))
+}
+
+// fn solve_fast<const N: usize>(inputs: &[usize]) -> usize {
+//     inputs.windows(N + 1).filter(|w| w[0] < w[N]).count()
+// }
+
+// #[aoc(day1, part1, fast)]
+// pub fn part1_fast(inputs: &[usize]) -> usize {
+//     solve_fast::<1>(inputs)
+// }
+
+// #[aoc(day1, part2, fast)]
+// pub fn part2_fast(inputs: &[usize]) -> usize {
+//     solve_fast::<3>(inputs)
+// }
+
+// #[aoc(day1, part2, fold)]
+// pub fn part2_fold(inputs: &[usize]) -> usize {
+//     inputs
+//         .windows(4)
+//         .fold((inputs[..3].iter().sum::<usize>(), 0), |(sum, count), w| {
+//             let new_sum = sum - w[0] + w[3];
+//             if new_sum > sum {
+//                 (new_sum, count + 1)
+//             } else {
+//                 (new_sum, count)
+//             }
+//         })
+//         .1
+// }
+
+// #[aoc(day1, part2, queue)]
+// pub fn part2_queue(inputs: &[usize]) -> usize {
+//     assert!(inputs.len() > 3);
+//     let mut windows = std::collections::VecDeque::with_capacity(3);
+//     windows.extend(&inputs[..3]);
+//     let first3: usize = inputs[..3].iter().sum();
+
+//     inputs[3..]
+//         .iter()
+//         .scan(first3, |sum, n| {
+//             let old = *sum;
+//             *sum = *sum - windows.pop_front().unwrap() + n;
+//             windows.push_back(n);
+//             Some(old < *sum)
+//         })
+//         .filter(|&x| x)
+//         .count()
+// }
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+
+    fn variants() -> Vec<Entry> {
+        vec![
+            Entry::new(Self::YEAR, 1, 1, Some("fastest"), generator, |p| {
+                part1_fastest(p)
+            }),
+            Entry::new(Self::YEAR, 1, 2, Some("fastest"), generator, |p| {
+                part2_fastest(p)
+            }),
+            Entry::new(
+                Self::YEAR,
+                1,
+                1,
+                Some("vectorized"),
+                generator_vectorized,
+                part1_vectorized,
+            ),
+            Entry::new(
+                Self::YEAR,
+                1,
+                2,
+                Some("vectorized"),
+                generator_vectorized,
+                part2_vectorized,
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1_fastest(&generator(SAMPLE).unwrap()), 7);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2_fastest(&generator(SAMPLE).unwrap()), 5);
+        assert_eq!(part2(&[usize::MAX; 4]), 0);
+    }
+
+    #[test]
+    pub fn test_vectorized() {
+        let depths = generator_vectorized(SAMPLE).unwrap();
+        assert!(matches!(depths, Depths::Narrow(_)));
+        assert_eq!(part1_vectorized(&depths), 7);
+        assert_eq!(part2_vectorized(&depths), 5);
+
+        // One depth too deep for a `u32` keeps the whole series wide
+        let depths = generator_vectorized("4294967296\n1\n2\n3\n4294967297").unwrap();
+        assert!(matches!(depths, Depths::Wide(_)));
+        assert_eq!(
+            (part1_vectorized(&depths), part2_vectorized(&depths)),
+            (3, 1)
+        );
+
+        let err = generator_vectorized("1\n2\nx").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        // Longer than a block, so the counts of several blocks add up
+        let long: Vec<u32> = (0..3 * BLOCK as u32 + 5).map(|d| d % 7).collect();
+        let wide: Vec<usize> = long.iter().map(|&d| d as usize).collect();
+        assert_eq!(count_increases(&long, 1), solve_fastest::<1>(&wide));
+        assert_eq!(count_increases(&long, 3), solve_fastest::<3>(&wide));
+        assert_eq!(count_increases(&long[..2], 3), 0);
+    }
+
+    #[test]
+    pub fn test_anomalies() {
+        let thresholds = Thresholds {
+            jump: 20,
+            plateau: 3,
+            median_window: 3,
+            outlier: 20,
+        };
+
+        assert_eq!(
+            find_anomalies(&generator(SAMPLE).unwrap(), &thresholds).unwrap(),
+            [
+                Anomaly::Jump {
+                    index: 6,
+                    from: 207,
+                    to: 240
+                },
+                Anomaly::Outlier {
+                    index: 6,
+                    median: 207
+                },
+                Anomaly::Jump {
+                    index: 7,
+                    from: 240,
+                    to: 269
+                },
+                Anomaly::Outlier {
+                    index: 7,
+                    median: 207
+                },
+            ]
+        );
+
+        // A stuck sensor: the plateau is flagged, and so is the reading that leaves it
+        let stuck = [100, 101, 101, 101, 101, 102, 102, 130];
+        assert_eq!(
+            find_anomalies(&stuck, &thresholds).unwrap(),
+            [
+                Anomaly::Plateau { start: 1, len: 4 },
+                Anomaly::Jump {
+                    index: 7,
+                    from: 102,
+                    to: 130
+                },
+                Anomaly::Outlier {
+                    index: 7,
+                    median: 102
+                },
+            ]
+        );
+
+        // An even window takes the lower middle depth
+        let even = Thresholds {
+            median_window: 2,
+            ..thresholds
+        };
+        assert_eq!(
+            find_anomalies(&[10, 50, 31], &even).unwrap(),
+            [
+                Anomaly::Jump {
+                    index: 1,
+                    from: 10,
+                    to: 50
+                },
+                Anomaly::Outlier {
+                    index: 2,
+                    median: 10
+                }
+            ]
+        );
+
+        assert_eq!(find_anomalies(&[], &thresholds), Some(vec![]));
+        assert_eq!(
+            find_anomalies(
+                &stuck,
+                &Thresholds {
+                    plateau: 1,
+                    ..thresholds
+                }
+            ),
+            None
+        );
+        assert_eq!(
+            find_anomalies(
+                &stuck,
+                &Thresholds {
+                    median_window: 0,
+                    ..thresholds
+                }
+            ),
+            None
+        );
+    }
+
+    #[test]
+    pub fn test_windows() {
+        let depths = generator(SAMPLE).unwrap();
+
+        let stats = analyze_windows(&depths, 1).unwrap();
+        assert_eq!((stats.increases, stats.decreases, stats.flat), (7, 2, 0));
+        assert_eq!(stats.longest_increase, 3);
+        assert_eq!(stats.rolling.len(), 10);
+        assert_eq!(stats.rolling[3].mean, 210.0);
+
+        let stats = analyze_windows(&depths, 3).unwrap();
+        assert_eq!((stats.increases, stats.decreases, stats.flat), (5, 1, 1));
+        assert_eq!(stats.longest_increase, 4);
+        assert_eq!(stats.rolling.len(), 8);
+        assert_eq!(
+            stats.rolling[0],
+            Rolling {
+                min: 199,
+                max: 208,
+                mean: 607.0 / 3.0
+            }
+        );
+
+        assert_eq!(analyze_windows(&depths, 11).unwrap(), Windows::default());
+        assert_eq!(analyze_windows(&depths, 0), None);
+        assert_eq!(
+            analyze_windows(&[usize::MAX; 3], 2).unwrap().rolling[1].max,
+            usize::MAX
+        );
+    }
+
+    #[test]
+    pub fn test_stream() {
+        assert_eq!(stream(SAMPLE.as_bytes()).unwrap(), (7, 5));
+        assert_eq!(stream("".as_bytes()).unwrap(), (0, 0));
+
+        let err = stream("199\n2OO\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 1: invalid digit found in string (`2OO`)"
+        );
+    }
+
+    mod variants {
+        use crate::solution::cross_check;
+        use itertools::Itertools;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn test_agree(depths in prop::collection::vec(0..10_000usize, 0..500)) {
+                let input = depths.iter().join("\n");
+
+                prop_assert!(cross_check(crate::y2021::YEAR, 1, &input).is_ok());
+                prop_assert_eq!(
+                    super::stream(input.as_bytes()).unwrap(),
+                    (super::part1(&depths), super::part2(&depths))
+                );
+            }
+
+            #[test]
+            fn test_vectorized(depths in prop::collection::vec(any::<u32>(), 0..2000), offset in 0..8usize) {
+                let wide: Vec<usize> = depths.iter().map(|&d| d as usize).collect();
+
+                prop_assert_eq!(
+                    super::count_increases(&depths, offset),
+                    wide.windows(offset + 1).filter(|w| w[0] < w[offset]).count()
+                );
+                prop_assert_eq!(super::count_increases(&depths, 1), super::solve_fastest::<1>(&wide));
+                prop_assert_eq!(super::count_increases(&depths, 3), super::solve_fastest::<3>(&wide));
+            }
+
+            #[test]
+            fn test_windows(depths in prop::collection::vec(0..100usize, 0..200), size in 1..10usize) {
+                let stats = super::analyze_windows(&depths, size).unwrap();
+
+                prop_assert_eq!(
+                    stats.increases,
+                    depths.windows(size + 1).filter(|w| w[0] < w[size]).count()
+                );
+                prop_assert_eq!(
+                    stats.increases + stats.decreases + stats.flat,
+                    depths.len().saturating_sub(size)
+                );
+                prop_assert_eq!(stats.rolling.len(), depths.windows(size).count());
+                for (w, rolling) in depths.windows(size).zip(&stats.rolling) {
+                    prop_assert_eq!(rolling.min, *w.iter().min().unwrap());
+                    prop_assert_eq!(rolling.max, *w.iter().max().unwrap());
+                }
+            }
+        }
+    }
+}
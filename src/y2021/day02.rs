@@ -0,0 +1,402 @@
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+    stream::{StreamError, for_each_line},
+};
+
+pub mod script;
+
+type Scalar = i32;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Forward(Scalar),
+    Down(Scalar),
+    Up(Scalar),
+}
+
+impl Direction {
+    fn parse(src: &Source, s: &str) -> Result<Self, ParseError> {
+        let (dir, amount) = src.expect(s.split_once(' '), s, "expected `<direction> <amount>`")?;
+        let amount = src.parse(amount)?;
+
+        Ok(match dir {
+            "forward" => Direction::Forward(amount),
+            "down" => Direction::Down(amount),
+            "up" => Direction::Up(amount),
+            _ => return Err(src.error(dir, "invalid direction")),
+        })
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&Source::new(2, s), s)
+    }
+}
+
+#[aoc_generator(day2)]
+pub fn generator(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let src = Source::new(2, input);
+    input
+        .lines()
+        .map(|line| Direction::parse(&src, line))
+        .collect()
+}
+
+/// How the submarine is steered: part 1's plain model moves its depth directly, part 2's turns its
+/// aim and only dives going forward.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Model {
+    Plain,
+    Aim,
+}
+
+/// Where the submarine is, the aim stays 0 under [`Model::Plain`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub hor: Scalar,
+    pub depth: Scalar,
+    pub aim: Scalar,
+}
+
+impl State {
+    /// The state after `dir`, or `None` if it goes further than a `Scalar`.
+    pub fn step(self, model: Model, dir: &Direction) -> Option<Self> {
+        let mut next = self;
+        match (model, *dir) {
+            (Model::Plain, Direction::Forward(amount)) => {
+                next.hor = self.hor.checked_add(amount)?
+            }
+            (Model::Plain, Direction::Down(amount)) => {
+                next.depth = self.depth.checked_add(amount)?
+            }
+            (Model::Plain, Direction::Up(amount)) => next.depth = self.depth.checked_sub(amount)?,
+            (Model::Aim, Direction::Forward(amount)) => {
+                next.hor = self.hor.checked_add(amount)?;
+                next.depth = self.depth.checked_add(self.aim.checked_mul(amount)?)?;
+            }
+            (Model::Aim, Direction::Down(amount)) => next.aim = self.aim.checked_add(amount)?,
+            (Model::Aim, Direction::Up(amount)) => next.aim = self.aim.checked_sub(amount)?,
+        }
+
+        Some(next)
+    }
+}
+
+// Both parts are checked, an input that goes deeper than a `Scalar` has no answer
+fn solve(inputs: &[Direction], model: Model) -> Option<Scalar> {
+    let end = inputs
+        .iter()
+        .try_fold(State::default(), |state, dir| state.step(model, dir))?;
+
+    end.depth.checked_mul(end.hor)
+}
+
+#[aoc(day2, part1)]
+pub fn part1(inputs: &[Direction]) -> Option<Scalar> {
+    solve(inputs, Model::Plain)
+}
+
+#[aoc(day2, part2)]
+pub fn part2(inputs: &[Direction]) -> Option<Scalar> {
+    solve(inputs, Model::Aim)
+}
+
+/// Why a course can't be followed, commands are counted from 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CourseError {
+    /// The command takes the submarine further than a `Scalar` holds.
+    Overflow { command: usize },
+    /// The command takes the submarine above the surface.
+    Surfaced { command: usize, depth: Scalar },
+}
+
+impl fmt::Display for CourseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CourseError::Overflow { command } => write!(f, "command {command} goes out of range"),
+            CourseError::Surfaced { command, depth } => {
+                write!(
+                    f,
+                    "command {command} surfaces the submarine at depth {depth}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CourseError {}
+
+/// Follows the course under `model`, returning the state after every command.
+///
+/// The puzzle's answers only look at the end, so [`part1`] and [`part2`] don't mind a course that
+/// surfaces along the way, but the submarine can't.
+pub fn simulate(inputs: &[Direction], model: Model) -> Result<Vec<State>, CourseError> {
+    let mut state = State::default();
+
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(i, dir)| {
+            state = state
+                .step(model, dir)
+                .ok_or(CourseError::Overflow { command: i + 1 })?;
+            if state.depth < 0 {
+                return Err(CourseError::Surfaced {
+                    command: i + 1,
+                    depth: state.depth,
+                });
+            }
+
+            Ok(state)
+        })
+        .collect()
+}
+
+/// Writes a course and its trajectory from [`simulate`] under `model` as CSV, a row for the start
+/// and then one per command.
+///
+/// Fails with [`io::ErrorKind::InvalidInput`], before writing anything, if there isn't one state
+/// per command or a state isn't where its command takes the one before.
+pub fn write_csv(
+    mut out: impl Write,
+    inputs: &[Direction],
+    trajectory: &[State],
+    model: Model,
+) -> io::Result<()> {
+    let invalid = |message| Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+    if inputs.len() != trajectory.len() {
+        return invalid(format!(
+            "{} commands but {} states",
+            inputs.len(),
+            trajectory.len()
+        ));
+    }
+
+    let mut prev = State::default();
+    for (i, (dir, &state)) in inputs.iter().zip(trajectory).enumerate() {
+        if prev.step(model, dir) != Some(state) {
+            return invalid(format!(
+                "state {} doesn't follow from command {}",
+                i + 1,
+                i + 1
+            ));
+        }
+        prev = state;
+    }
+
+    writeln!(out, "step,command,amount,horizontal,depth,aim")?;
+    writeln!(out, "0,,,0,0,0")?;
+
+    for (i, (dir, state)) in inputs.iter().zip(trajectory).enumerate() {
+        let (command, amount) = match *dir {
+            Direction::Forward(amount) => ("forward", amount),
+            Direction::Down(amount) => ("down", amount),
+            Direction::Up(amount) => ("up", amount),
+        };
+        writeln!(
+            out,
+            "{},{command},{amount},{},{},{}",
+            i + 1,
+            state.hor,
+            state.depth,
+            state.aim
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Tracks the submarine under both parts' rules while reading, one command at a time.
+///
+/// A command that takes either model further than a `Scalar` is reported at its line.
+pub fn stream<R: BufRead>(reader: R) -> Result<(Scalar, Scalar), StreamError> {
+    let (mut plain, mut aim) = (State::default(), State::default());
+
+    for_each_line(2, reader, |src, line| {
+        let dir = Direction::parse(src, line)?;
+        let out_of_range = || src.error(line, "goes out of range");
+        plain = plain.step(Model::Plain, &dir).ok_or_else(out_of_range)?;
+        aim = aim.step(Model::Aim, &dir).ok_or_else(out_of_range)?;
+
+        Ok(())
+    })?;
+
+    let answer = |end: State| end.depth.checked_mul(end.hor).ok_or(StreamError::Overflow);
+    Ok((answer(plain)?, answer(aim)?))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Direction>;
+    type Answer1 = Option<Scalar>;
+    type Answer2 = Option<Scalar>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Option<Scalar> {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<Scalar> {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    pub fn test_input() {
+        // println!("{:?}", generator(SAMPLE));
+        use super::Direction::*;
+
+        assert_eq!(
+            generator(SAMPLE).unwrap(),
+            vec![Forward(5), Down(5), Forward(8), Up(3), Down(8), Forward(2)]
+        );
+    }
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), Some(150));
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), Some(900));
+        assert_eq!(
+            part2(&generator("down 65536\nforward 65536").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    pub fn test_simulate() {
+        let course = generator(SAMPLE).unwrap();
+
+        let plain = simulate(&course, Model::Plain).unwrap();
+        assert_eq!(plain.len(), 6);
+        assert_eq!(
+            plain[3],
+            State {
+                hor: 13,
+                depth: 2,
+                aim: 0
+            }
+        );
+
+        let aim = simulate(&course, Model::Aim).unwrap();
+        assert_eq!(
+            aim.last(),
+            Some(&State {
+                hor: 15,
+                depth: 60,
+                aim: 10
+            })
+        );
+
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &course[..2], &aim[..2], Model::Aim).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "step,command,amount,horizontal,depth,aim\n0,,,0,0,0\n1,forward,5,5,0,0\n2,down,5,5,0,5\n"
+        );
+
+        let err = write_csv(Vec::new(), &course[..2], &aim, Model::Aim).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        // The right length, but stepped under the other model
+        let mut csv = Vec::new();
+        let err = write_csv(&mut csv, &course, &aim, Model::Plain).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "state 2 doesn't follow from command 2");
+        assert!(csv.is_empty());
+    }
+
+    #[test]
+    pub fn test_surfaced() {
+        // Part 1 still has an answer, but the submarine went above the surface to get it
+        let course = generator("down 2\nup 3\ndown 4\nforward 1").unwrap();
+        assert_eq!(part1(&course), Some(3));
+        assert_eq!(
+            simulate(&course, Model::Plain),
+            Err(CourseError::Surfaced {
+                command: 2,
+                depth: -1
+            })
+        );
+        assert!(simulate(&course, Model::Aim).is_ok());
+
+        // Under the aim model only going forward changes the depth
+        let course = generator("up 1\nforward 2").unwrap();
+        assert_eq!(
+            simulate(&course, Model::Aim).unwrap_err().to_string(),
+            "command 2 surfaces the submarine at depth -2"
+        );
+
+        let course = generator("down 2147483647\ndown 1").unwrap();
+        assert_eq!(
+            simulate(&course, Model::Plain),
+            Err(CourseError::Overflow { command: 2 })
+        );
+    }
+
+    #[test]
+    pub fn test_stream() {
+        assert_eq!(stream(SAMPLE.as_bytes()).unwrap(), (150, 900));
+
+        let input = crate::r#gen::find(crate::y2021::YEAR, 2)
+            .unwrap()
+            .generate(0, 50);
+        let parsed = generator(&input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes())
+                .map(|(a, b)| (Some(a), Some(b)))
+                .unwrap(),
+            (part1(&parsed), part2(&parsed))
+        );
+
+        let err = stream("forward 5\nsideways 2".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 2, column 1: invalid direction (`sideways`)"
+        );
+
+        let err = stream("down 65536\nforward 65536".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 2, column 1: goes out of range (`forward 65536`)"
+        );
+        assert!(matches!(
+            stream("down 1\nforward 65536\ndown 65535".as_bytes()),
+            Err(StreamError::Overflow)
+        ));
+    }
+}
@@ -0,0 +1,550 @@
+//! A small language for writing day 2 courses compactly, expanded into plain [`Direction`]s.
+//!
+//! ```text
+//! # Comments run to the end of the line
+//! macro dive {
+//!     down 5
+//!     forward 2
+//! }
+//!
+//! include "approach.sub"
+//! repeat 3 {
+//!     dive
+//!     back 1
+//! }
+//! ```
+//!
+//! `forward`, `down` and `up` are the puzzle's commands, `back N` is `forward -N`. A macro can be
+//! used once it's defined, including by files included after it. An include is relative to the
+//! script it's written in, even inside a macro run from another one. Every plain day 2 input is
+//! already a script.
+
+use std::{fmt, fs, io, path::Path};
+
+use rustc_hash::FxHashMap as HashMap;
+
+use super::{Direction, Scalar};
+use crate::error::{ParseError, Source};
+
+/// A script, before its macros, repeats and includes are expanded.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    Move(Direction),
+    /// Moves backwards, the same as going forward by the negated amount.
+    Back(Scalar),
+    /// Runs the block the given number of times.
+    Repeat(u32, Vec<Command>),
+    /// Defines a macro for the commands after it.
+    Macro(String, Vec<Command>),
+    /// Runs a macro.
+    Call(String),
+    /// Runs another script, at the path as written in the script.
+    Include(String),
+}
+
+/// Why a script can't be expanded.
+#[derive(Debug)]
+pub enum ScriptError {
+    Parse(ParseError),
+    /// An included script failed, `path` as written in the script that included it.
+    Include {
+        path: String,
+        error: Box<ScriptError>,
+    },
+    Io {
+        path: String,
+        error: io::Error,
+    },
+    /// A macro used before it was defined.
+    UnknownMacro(String),
+    /// A macro that uses itself, or a script that includes itself.
+    Recursive(String),
+    /// The script expands to more than [`MAX_DIRECTIONS`].
+    TooLong,
+    /// Blocks, macros and includes are nested more than 64 deep between them.
+    TooDeep,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Parse(e) => write!(f, "{e}"),
+            ScriptError::Include { path, error } => write!(f, "in {path}: {error}"),
+            ScriptError::Io { path, error } => write!(f, "unable to read {path}: {error}"),
+            ScriptError::UnknownMacro(name) => write!(f, "unknown macro `{name}`"),
+            ScriptError::Recursive(name) => write!(f, "`{name}` uses itself"),
+            ScriptError::TooLong => write!(f, "expands to more than {MAX_DIRECTIONS} commands"),
+            ScriptError::TooDeep => {
+                write!(f, "nests more than {MAX_DEPTH} blocks, macros and includes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl From<ParseError> for ScriptError {
+    fn from(e: ParseError) -> Self {
+        ScriptError::Parse(e)
+    }
+}
+
+/// The most commands a script can expand to, so a few nested repeats can't eat all the memory.
+pub const MAX_DIRECTIONS: usize = 1 << 24;
+
+// Deeper than any course needs, shallow enough that parsing or expanding can't overflow the stack
+const MAX_DEPTH: usize = 64;
+
+const KEYWORDS: [&str; 7] = [
+    "forward", "down", "up", "back", "repeat", "macro", "include",
+];
+
+fn is_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&s)
+}
+
+/// Parses a script into its commands, without reading any includes.
+pub fn parse_script(input: &str) -> Result<Vec<Command>, ParseError> {
+    let src = Source::new(2, input);
+
+    parse_block(&src, &mut input.lines(), None, 0)
+}
+
+// Parses commands up to the `}` closing `open`, or to the end of the script
+fn parse_block<'a>(
+    src: &Source,
+    lines: &mut impl Iterator<Item = &'a str>,
+    open: Option<&'a str>,
+    depth: usize,
+) -> Result<Vec<Command>, ParseError> {
+    let mut commands = Vec::new();
+
+    while let Some(line) = lines.next() {
+        let code = line.split_once('#').map_or(line, |(code, _)| code).trim();
+        if code.is_empty() {
+            continue;
+        }
+        if code == "}" {
+            return match open {
+                Some(_) => Ok(commands),
+                None => Err(src.error(code, "`}` without a block to close")),
+            };
+        }
+
+        let (word, rest) = code
+            .split_once(char::is_whitespace)
+            .map_or((code, ""), |(word, rest)| (word, rest.trim()));
+        let block_header = |rest: &'a str| {
+            let header = rest.strip_suffix('{').map(str::trim_end);
+            if depth >= MAX_DEPTH {
+                return Err(src.error(code, "blocks are nested too deep"));
+            }
+
+            src.expect(header, code, &format!("expected `{word} <...> {{`"))
+        };
+
+        commands.push(match word {
+            "forward" | "down" | "up" => Command::Move(Direction::parse(src, code)?),
+            "back" => {
+                let amount: Scalar = src.parse(rest)?;
+                src.expect(amount.checked_neg(), rest, "amount is out of range")?;
+
+                Command::Back(amount)
+            }
+            "repeat" => {
+                let count = src.parse(block_header(rest)?)?;
+
+                Command::Repeat(count, parse_block(src, lines, Some(code), depth + 1)?)
+            }
+            "macro" => {
+                let name = block_header(rest)?;
+                if !is_name(name) {
+                    return Err(src.error(name, "expected a macro name"));
+                }
+
+                Command::Macro(
+                    name.to_string(),
+                    parse_block(src, lines, Some(code), depth + 1)?,
+                )
+            }
+            "include" => {
+                let path = rest
+                    .strip_prefix('"')
+                    .and_then(|path| path.strip_suffix('"'))
+                    .unwrap_or(rest);
+                if path.is_empty() {
+                    return Err(src.error(code, "expected `include \"<path>\"`"));
+                }
+
+                Command::Include(path.to_string())
+            }
+            _ if rest.is_empty() && is_name(word) => Command::Call(word.to_string()),
+            _ => return Err(src.error(word, "unknown command")),
+        });
+    }
+
+    match open {
+        Some(open) => Err(src.error(open, "block is never closed")),
+        None => Ok(commands),
+    }
+}
+
+struct Expander<R> {
+    resolve: R,
+    // Each macro's body, with the script it's defined in for the includes it runs
+    macros: HashMap<String, (Option<String>, Vec<Command>)>,
+    // The script being expanded, as named by `resolve`, `None` for the one given to `expand_script`
+    script: Option<String>,
+    // The macros and includes being expanded, innermost last
+    stack: Vec<String>,
+    // How many repeats, macros and includes are being expanded, each one is a level of recursion
+    depth: usize,
+    directions: Vec<Direction>,
+}
+
+impl<R: FnMut(Option<&str>, &str) -> io::Result<(String, String)>> Expander<R> {
+    fn push(&mut self, direction: Direction) -> Result<(), ScriptError> {
+        if self.directions.len() == MAX_DIRECTIONS {
+            return Err(ScriptError::TooLong);
+        }
+
+        self.directions.push(direction);
+        Ok(())
+    }
+
+    // Expands a block a level deeper, as long as that's within `MAX_DEPTH`
+    fn deeper(&mut self, commands: &[Command]) -> Result<(), ScriptError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ScriptError::TooDeep);
+        }
+
+        self.depth += 1;
+        self.expand(commands)?;
+        self.depth -= 1;
+
+        Ok(())
+    }
+
+    // Runs `name`'s `commands`, written in `script`, unless it's already running
+    fn nested(
+        &mut self,
+        name: &str,
+        script: Option<String>,
+        commands: &[Command],
+    ) -> Result<(), ScriptError> {
+        if self.stack.iter().any(|n| n == name) {
+            return Err(ScriptError::Recursive(name.to_string()));
+        }
+
+        self.stack.push(name.to_string());
+        let outer = std::mem::replace(&mut self.script, script);
+        self.deeper(commands)?;
+        self.script = outer;
+        self.stack.pop();
+
+        Ok(())
+    }
+
+    fn expand(&mut self, commands: &[Command]) -> Result<(), ScriptError> {
+        for command in commands {
+            match command {
+                Command::Move(direction) => self.push(*direction)?,
+                Command::Back(amount) => self.push(Direction::Forward(-amount))?,
+                Command::Repeat(0, _) => {}
+                Command::Repeat(count, body) => {
+                    // Every pass expands the same, so expand once and copy it
+                    let start = self.directions.len();
+                    self.deeper(body)?;
+
+                    let pass = self.directions.len() - start;
+                    let total = (*count as usize)
+                        .checked_mul(pass)
+                        .and_then(|n| n.checked_add(start))
+                        .filter(|&n| n <= MAX_DIRECTIONS)
+                        .ok_or(ScriptError::TooLong)?;
+                    self.directions.reserve(total - self.directions.len());
+                    for _ in 1..*count {
+                        self.directions.extend_from_within(start..start + pass);
+                    }
+                }
+                Command::Macro(name, body) => {
+                    let defined = (self.script.clone(), body.clone());
+                    self.macros.insert(name.clone(), defined);
+                }
+                Command::Call(name) => {
+                    let (script, body) = self
+                        .macros
+                        .get(name)
+                        .ok_or_else(|| ScriptError::UnknownMacro(name.clone()))?
+                        .clone();
+                    self.nested(name, script, &body)?;
+                }
+                Command::Include(path) => {
+                    let resolved = (self.resolve)(self.script.as_deref(), path);
+                    if let Ok((name, _)) = &resolved
+                        && self.stack.contains(name)
+                    {
+                        return Err(ScriptError::Recursive(name.clone()));
+                    }
+
+                    let included = resolved
+                        .map_err(|error| ScriptError::Io {
+                            path: path.clone(),
+                            error,
+                        })
+                        .and_then(|(name, script)| {
+                            let commands = parse_script(&script)?;
+                            self.nested(&name.clone(), Some(name), &commands)
+                        });
+
+                    included.map_err(|error| ScriptError::Include {
+                        path: path.clone(),
+                        error: Box::new(error),
+                    })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Expands a script into the course it describes, reading includes through `resolve`.
+///
+/// `resolve` gets the name of the script with the include, `None` for `input`, and the path as
+/// written. It returns the included script's name, the same whichever way it's reached so a
+/// script including itself is caught, and its text.
+pub fn expand_script(
+    input: &str,
+    resolve: impl FnMut(Option<&str>, &str) -> io::Result<(String, String)>,
+) -> Result<Vec<Direction>, ScriptError> {
+    let commands = parse_script(input)?;
+    let mut expander = Expander {
+        resolve,
+        macros: HashMap::default(),
+        script: None,
+        stack: Vec::new(),
+        depth: 0,
+        directions: Vec::new(),
+    };
+
+    expander.expand(&commands)?;
+    Ok(expander.directions)
+}
+
+/// Expands a script in `dir` with its includes, and theirs, each read relative to the directory of
+/// the script including it.
+pub fn expand_in(input: &str, dir: &Path) -> Result<Vec<Direction>, ScriptError> {
+    expand_script(input, |from, path| {
+        let dir = from
+            .and_then(|from| Path::new(from).parent())
+            .unwrap_or(dir);
+        // Canonical, so `a.sub` and `./sub/../a.sub` are the same script
+        let path = fs::canonicalize(dir.join(path))?;
+        let script = fs::read_to_string(&path)?;
+        let name = path
+            .into_os_string()
+            .into_string()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "path is not valid UTF-8"))?;
+
+        Ok((name, script))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2021::day02::{Direction::*, generator};
+
+    // Scripts read from memory, keyed by path, each include relative to its script's directory
+    fn files<'a>(
+        files: &'a [(&str, &str)],
+    ) -> impl FnMut(Option<&str>, &str) -> io::Result<(String, String)> + 'a {
+        |from, path| {
+            let path = match from.and_then(|from| from.rsplit_once('/')) {
+                Some((dir, _)) => format!("{dir}/{path}"),
+                None => path.to_string(),
+            };
+
+            files
+                .iter()
+                .find(|(name, _)| *name == path)
+                .map(|(_, script)| (path, script.to_string()))
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+        }
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let script = "# A dive\nmacro dive {\n  down 5 # steeply\n  forward 2\n}\n\nrepeat 2 {\n  dive\n  back 1\n}\ninclude \"rest.sub\"";
+
+        assert_eq!(
+            parse_script(script).unwrap(),
+            [
+                Command::Macro(
+                    "dive".to_string(),
+                    vec![Command::Move(Down(5)), Command::Move(Forward(2))]
+                ),
+                Command::Repeat(2, vec![Command::Call("dive".to_string()), Command::Back(1)]),
+                Command::Include("rest.sub".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_expand() {
+        let script = "macro dive {\n  down 5\n  forward 2\n}\nrepeat 2 {\n  dive\n  back 1\n}\ninclude \"rest.sub\"";
+        let rest = [("rest.sub", "up 3\ndive")];
+
+        assert_eq!(
+            expand_script(script, files(&rest)).unwrap(),
+            [
+                Down(5),
+                Forward(2),
+                Forward(-1),
+                Down(5),
+                Forward(2),
+                Forward(-1),
+                Up(3),
+                Down(5),
+                Forward(2),
+            ]
+        );
+
+        // A plain course is a script of itself
+        let course = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+        assert_eq!(
+            expand_script(course, files(&[])).unwrap(),
+            generator(course).unwrap()
+        );
+
+        let nested = "repeat 3 {\n  repeat 0 {\n    up 1\n  }\n  repeat 2 {\n    down 1\n  }\n}";
+        assert_eq!(expand_script(nested, files(&[])).unwrap(), [Down(1); 6]);
+    }
+
+    #[test]
+    pub fn test_include_relative() {
+        let tree = [
+            ("dive.sub", "down 1"),
+            (
+                "sub/a.sub",
+                "include \"dive.sub\"\nmacro dive {\n  include \"dive.sub\"\n}",
+            ),
+            ("sub/dive.sub", "down 2"),
+        ];
+
+        // The macro's include is relative to `sub/a.sub` wherever it's run
+        let script = "include \"sub/a.sub\"\ninclude \"dive.sub\"\ndive";
+        assert_eq!(
+            expand_script(script, files(&tree)).unwrap(),
+            [Down(2), Down(1), Down(2)]
+        );
+    }
+
+    #[test]
+    pub fn test_expand_in() {
+        let dir = std::env::temp_dir().join(format!("aoc2021-script-{}", std::process::id()));
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("sub/a.sub"), "down 1\ninclude \"b.sub\"").unwrap();
+        fs::write(dir.join("sub/b.sub"), "forward 2").unwrap();
+        fs::write(dir.join("sub/loop.sub"), "include \"../sub/./loop.sub\"").unwrap();
+
+        let directions = expand_in("include \"sub/a.sub\"", &dir);
+        let looped = expand_in("include \"sub/loop.sub\"", &dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(directions.unwrap(), [Down(1), Forward(2)]);
+        let err = looped.unwrap_err().to_string();
+        assert!(err.ends_with("loop.sub` uses itself"), "{err}");
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        for (script, line, reason) in [
+            ("forward 1\nsideways 2", 2, "unknown command"),
+            ("repeat 2 {\n  down 1", 1, "block is never closed"),
+            ("down 1\n}", 2, "`}` without a block to close"),
+            ("repeat two {\n}", 1, "invalid digit found in string"),
+            ("repeat 2\ndown 1", 1, "expected `repeat <...> {`"),
+            ("macro up {\n}", 1, "expected a macro name"),
+            ("back -2147483648", 1, "amount is out of range"),
+            ("include \"\"", 1, "expected `include \"<path>\"`"),
+        ] {
+            let err = parse_script(script).unwrap_err();
+            assert_eq!(
+                (err.line, err.reason.as_str()),
+                (line, reason),
+                "{script:?}"
+            );
+        }
+
+        let deep = "repeat 1 {\n".repeat(100);
+        assert_eq!(
+            parse_script(&deep).unwrap_err().reason,
+            "blocks are nested too deep"
+        );
+    }
+
+    #[test]
+    pub fn test_expand_errors() {
+        let err = expand_script("dive\nmacro dive {\n}", files(&[])).unwrap_err();
+        assert_eq!(err.to_string(), "unknown macro `dive`");
+
+        let err = expand_script("macro a {\n  b\n}\nmacro b {\n  a\n}\na", files(&[])).unwrap_err();
+        assert_eq!(err.to_string(), "`a` uses itself");
+
+        let loops = [
+            ("a.sub", "include \"b.sub\""),
+            ("b.sub", "include \"a.sub\""),
+        ];
+        let err = expand_script("include \"a.sub\"", files(&loops)).unwrap_err();
+        assert_eq!(err.to_string(), "in a.sub: in b.sub: `a.sub` uses itself");
+
+        let bad = [("bad.sub", "down 1\nforward x")];
+        let err = expand_script("include bad.sub", files(&bad)).unwrap_err();
+        assert!(
+            matches!(&err, ScriptError::Include { error, .. } if matches!(**error, ScriptError::Parse(ParseError { line: 2, .. }))),
+            "{err}"
+        );
+
+        let err = expand_script("include missing.sub", files(&[])).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("in missing.sub: unable to read missing.sub")
+        );
+
+        // Each macro only uses the one before it, but calling the last still recurses through them all
+        let chain: String = (1..5000)
+            .map(|i| format!("macro m{i} {{\n  m{}\n}}\n", i - 1))
+            .collect();
+        let script = format!("macro m0 {{\n  forward 1\n}}\n{chain}m4999");
+        assert!(matches!(
+            expand_script(&script, files(&[])),
+            Err(ScriptError::TooDeep)
+        ));
+        let script = format!("macro m0 {{\n  forward 1\n}}\n{chain}m63");
+        assert_eq!(expand_script(&script, files(&[])).unwrap(), [Forward(1)]);
+
+        let includes: Vec<_> = (0..100)
+            .map(|i| (format!("{i}.sub"), format!("include {}.sub", i + 1)))
+            .collect();
+        let includes: Vec<_> = includes
+            .iter()
+            .map(|(path, script)| (path.as_str(), script.as_str()))
+            .collect();
+        let err = expand_script("include 0.sub", files(&includes)).unwrap_err();
+        assert!(
+            err.to_string()
+                .ends_with("in 64.sub: nests more than 64 blocks, macros and includes"),
+            "{err}"
+        );
+
+        let huge = "repeat 4096 {\n  repeat 4096 {\n    forward 1\n    forward 1\n  }\n}";
+        assert!(matches!(
+            expand_script(huge, files(&[])),
+            Err(ScriptError::TooLong)
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::{Entry, Solution},
+};
+
+// Every line has as many digits as the first, at most `max` so gamma times epsilon fits
+fn parse_width(src: &Source, input: &str, max: usize) -> Result<usize, ParseError> {
+    let first = input.lines().next().unwrap_or(input);
+
+    if (1..=max).contains(&first.len()) {
+        Ok(first.len())
+    } else {
+        Err(src.error(first, format!("expected 1 to {max} digits")))
+    }
+}
+
+#[aoc_generator(day3)]
+pub fn generator(input: &str) -> Result<Vec<String>, ParseError> {
+    let src = Source::new(3, input);
+    let width = parse_width(&src, input, usize::BITS as usize / 2)?;
+
+    input
+        .lines()
+        .map(|s| parse_line(&src, s, width).map(str::to_owned))
+        .collect()
+}
+
+fn parse_line<'a>(src: &Source, s: &'a str, width: usize) -> Result<&'a str, ParseError> {
+    if let Some((i, c)) = s.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+        Err(src.error(&s[i..i + c.len_utf8()], "expected a binary digit"))
+    } else if s.len() != width {
+        Err(src.error(s, format!("expected {width} digits")))
+    } else {
+        Ok(s)
+    }
+}
+
+#[aoc(day3, part1)]
+pub fn part1(lines: &[String]) -> usize {
+    let mut freq = vec![0; lines[0].len()];
+
+    for l in lines {
+        for (b, f) in l.bytes().zip(freq.iter_mut()) {
+            if b == b'1' {
+                *f += 1;
+            }
+        }
+    }
+
+    let threshold = lines.len() / 2;
+    let (gamma, epsilon) = freq.iter().fold((0, 0), |(g, e), &x| {
+        let (gx, ge) = if x > threshold { (1, 0) } else { (0, 1) };
+        (g * 2 + gx, e * 2 + ge)
+    });
+
+    gamma * epsilon
+}
+
+fn solve_part2(lines: &[String], prefer: u8, dislike: u8) -> usize {
+    let mut data: Vec<&String> = lines.iter().collect();
+
+    for pos in 0..lines[0].len() {
+        let counts = data
+            .iter()
+            .map(|l| l.as_bytes()[pos])
+            .filter(|&x| x == b'1')
+            .count();
+
+        // Every remaining line agrees on this bit, so there is nothing to filter out
+        if counts == 0 || counts == data.len() {
+            continue;
+        }
+
+        let common = if counts * 2 < data.len() {
+            prefer
+        } else {
+            dislike
+        };
+
+        data.retain(|l| l.as_bytes()[pos] == common);
+        if data.len() == 1 {
+            break;
+        }
+    }
+
+    usize::from_str_radix(data[0], 2).unwrap()
+}
+
+#[aoc(day3, part2)]
+pub fn part2(lines: &[String]) -> usize {
+    solve_part2(lines, b'1', b'0') * solve_part2(lines, b'0', b'1')
+}
+
+#[aoc_generator(day3, part1, Int)]
+#[aoc_generator(day3, part2, Int)]
+pub fn generator_int(input: &str) -> Result<(usize, Vec<u16>), ParseError> {
+    let src = Source::new(3, input);
+    let width = parse_width(&src, input, u16::BITS as usize)?;
+
+    let nums = input
+        .lines()
+        .map(|s| {
+            let s = parse_line(&src, s, width)?;
+            Ok(s.bytes().fold(0, |n, b| n * 2 + u16::from(b - b'0')))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((width, nums))
+}
+
+#[aoc(day3, part1, Int)]
+pub fn part1_int((width, nums): &(usize, Vec<u16>)) -> usize {
+    let threshold = nums.len() / 2;
+
+    let mut gamma = 0;
+    let mut epsilon = 0;
+
+    for n in 0..*width {
+        let count = nums.iter().filter(|&&x| x >> n & 0b1 > 0).count();
+
+        let (dx, de) = if count > threshold { (1, 0) } else { (0, 1) };
+        gamma |= dx << n;
+        epsilon |= de << n;
+    }
+
+    gamma * epsilon
+}
+
+fn solve_part2_int(nums: &[u16], width: usize, prefer: u16, dislike: u16) -> usize {
+    let mut data: Vec<_> = nums.to_vec();
+
+    for pos in (0..width).rev() {
+        let mask = 1 << pos;
+        let counts = data.iter().filter(|&&n| n & mask > 0).count();
+        if counts == 0 || counts == data.len() {
+            continue;
+        }
+        let common = if counts * 2 < data.len() {
+            prefer
+        } else {
+            dislike
+        };
+
+        data.retain(|&n| (n & mask) >> pos == common);
+        if data.len() == 1 {
+            break;
+        }
+    }
+
+    usize::from(data[0])
+}
+
+#[aoc(day3, part2, Int)]
+pub fn part2_int((width, nums): &(usize, Vec<u16>)) -> usize {
+    solve_part2_int(nums, *width, 1, 0) * solve_part2_int(nums, *width, 0, 1)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+
+    fn variants() -> Vec<Entry> {
+        vec![
+            Entry::new(Self::YEAR, 3, 1, Some("Int"), generator_int, part1_int),
+            Entry::new(Self::YEAR, 3, 2, Some("Int"), generator_int, part2_int),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 198);
+        assert_eq!(part1_int(&generator_int(SAMPLE).unwrap()), 198);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 230);
+        assert_eq!(part2_int(&generator_int(SAMPLE).unwrap()), 230);
+    }
+
+    #[test]
+    pub fn test_width() {
+        assert_eq!(generator("").unwrap_err().reason, "expected 1 to 32 digits");
+        assert!(generator(&"1".repeat(33)).is_err());
+        assert!(generator_int(&"1".repeat(17)).is_err());
+        assert!(generator_int("\n").is_err());
+    }
+
+    mod variants {
+        use crate::solution::cross_check;
+        use itertools::Itertools;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn test_agree(width in 1..=16usize, nums in prop::collection::vec(any::<u16>(), 1..200)) {
+                let input = nums
+                    .iter()
+                    .map(|n| format!("{:0width$b}", u32::from(*n) & ((1 << width) - 1)))
+                    .join("\n");
+
+                prop_assert!(cross_check(crate::y2021::YEAR, 3, &input).is_ok(), "{:?}", cross_check(crate::y2021::YEAR, 3, &input));
+            }
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use std::str::FromStr;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+    trace::{Event, NoTrace, Tracer},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawBingo"))]
+pub struct Bingo {
+    draw: Vec<usize>,
+    boards: Vec<Board>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawBoard"))]
+pub struct Board {
+    row: [[Option<u8>; 5]; 5], // row major version of the board
+    col: [[Option<u8>; 5]; 5], // column major version of the board
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_array"))]
+    index: [Option<(usize, usize)>; 100], // lookup table for the (r,c) location
+    score: usize,              // keep track of the total unmarked score
+    winner: bool,              // keep track if we've won
+}
+
+impl Board {
+    fn parse(src: &Source, s: &str) -> Result<Self, ParseError> {
+        let mut row = [[None; 5]; 5];
+        let mut col = [[None; 5]; 5];
+
+        let mut index = [None; 100];
+        let mut score = 0;
+        let mut rows = 0;
+
+        for (r, x) in s.lines().enumerate() {
+            if r >= 5 {
+                return Err(src.error(x, "expected 5 rows"));
+            }
+
+            let mut cols = 0;
+            // split_whitespace so we can handle the extra spaces used for alignment
+            for (c, nstr) in x.split_whitespace().enumerate() {
+                if c >= 5 {
+                    return Err(src.error(nstr, "expected 5 numbers in a row"));
+                }
+
+                let n = parse_number(src, nstr)?;
+                if index[n].is_some() {
+                    return Err(src.error(nstr, "number is already on the board"));
+                }
+
+                // precompute everything to save time when processing
+                index[n] = Some((r, c));
+                row[r][c] = Some(n as u8);
+                col[c][r] = Some(n as u8);
+                score += n;
+                cols += 1;
+            }
+
+            if cols < 5 {
+                return Err(src.error(x, "expected 5 numbers in a row"));
+            }
+            rows += 1;
+        }
+
+        if rows < 5 {
+            return Err(src.error(s, "expected 5 rows"));
+        }
+
+        Ok(Self {
+            row,
+            col,
+            index,
+            score,
+            winner: false,
+        })
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&Source::new(4, s), s)
+    }
+}
+
+// Bingo numbers are used as an index into Board::index, so they have to be below 100
+fn parse_number(src: &Source, s: &str) -> Result<usize, ParseError> {
+    let n: usize = src.parse(s)?;
+    if n < 100 {
+        Ok(n)
+    } else {
+        Err(src.error(s, "number must be below 100"))
+    }
+}
+
+// The models as serialized, checked before they're used since the numbers index `Board::index`
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawBingo {
+    draw: Vec<usize>,
+    boards: Vec<Board>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBingo> for Bingo {
+    type Error = &'static str;
+
+    fn try_from(raw: RawBingo) -> Result<Self, Self::Error> {
+        if raw.draw.iter().any(|&n| n >= 100) {
+            return Err("drawn numbers must be below 100");
+        }
+
+        Ok(Self {
+            draw: raw.draw,
+            boards: raw.boards,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawBoard {
+    row: [[Option<u8>; 5]; 5],
+    col: [[Option<u8>; 5]; 5],
+    #[serde(with = "crate::utils::serde_array")]
+    index: [Option<(usize, usize)>; 100],
+    score: usize,
+    winner: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBoard> for Board {
+    type Error = &'static str;
+
+    // Rebuilds everything else from `row`, the way `parse` does, and checks it all matches
+    fn try_from(raw: RawBoard) -> Result<Self, Self::Error> {
+        let mut board = Self {
+            row: raw.row,
+            col: [[None; 5]; 5],
+            index: [None; 100],
+            score: 0,
+            winner: raw.winner,
+        };
+
+        for (r, row) in raw.row.iter().enumerate() {
+            for (c, &n) in row.iter().enumerate() {
+                let Some(n) = n else { continue };
+                let n = usize::from(n);
+                if n >= 100 || board.index[n].is_some() {
+                    return Err("board numbers must be below 100 and different");
+                }
+
+                board.index[n] = Some((r, c));
+                board.col[c][r] = Some(n as u8);
+                board.score += n;
+            }
+        }
+
+        if (board.col, board.index, board.score) != (raw.col, raw.index, raw.score) {
+            return Err("board columns, index and score don't match its rows");
+        }
+
+        Ok(board)
+    }
+}
+
+impl Board {
+    // Pass the (r, c) to cheaply check for bingo
+    fn check_bingo(&mut self, r: usize, c: usize) -> bool {
+        if self.row[r].iter().all(|&cell| cell.is_none())
+            || self.col[c].iter().all(|&cell| cell.is_none())
+        {
+            self.winner = true;
+        }
+
+        self.winner
+    }
+
+    fn set_num(&mut self, num: usize) -> bool {
+        // Taken so a number drawn twice only counts once
+        if let Some((r, c)) = self.index[num].take() {
+            self.row[r][c] = None;
+            self.col[c][r] = None;
+            self.score -= num;
+            self.check_bingo(r, c)
+        } else {
+            false
+        }
+    }
+}
+
+#[aoc_generator(day4)]
+pub fn generator(input: &str) -> Result<Bingo, ParseError> {
+    let src = Source::new(4, input);
+    let mut itr = input.split("\n\n");
+    let first = itr.next().unwrap_or_default();
+    let draw: Vec<_> = first
+        .split(',')
+        .map(|x| parse_number(&src, x))
+        .collect::<Result<_, _>>()?;
+
+    let boards: Vec<_> = itr
+        .map(|s| Board::parse(&src, s))
+        .collect::<Result<_, _>>()?;
+    if boards.is_empty() {
+        return Err(src.error(input, "expected at least one board"));
+    }
+
+    Ok(Bingo { draw, boards })
+}
+
+/// The score of the first board to win, `None` if none ever does.
+#[aoc(day4, part1)]
+pub fn part1(inputs: &Bingo) -> Option<usize> {
+    part1_traced(inputs, &mut NoTrace)
+}
+
+/// [`part1`], tracing every draw and the winning board.
+pub fn part1_traced(inputs: &Bingo, tracer: &mut impl Tracer) -> Option<usize> {
+    let mut boards = inputs.boards.to_vec();
+    for &n in &inputs.draw {
+        tracer.trace(|| Event::Draw { number: n });
+        for (i, b) in boards.iter_mut().enumerate() {
+            if b.set_num(n) {
+                tracer.trace(|| Event::Win {
+                    board: i,
+                    score: b.score * n,
+                });
+                return Some(b.score * n);
+            }
+        }
+    }
+
+    None
+}
+
+/// The score of the last board to win, `None` if some board never does.
+#[aoc(day4, part2)]
+pub fn part2(inputs: &Bingo) -> Option<usize> {
+    part2_traced(inputs, &mut NoTrace)
+}
+
+/// [`part2`], tracing every draw and every board as it wins.
+pub fn part2_traced(inputs: &Bingo, tracer: &mut impl Tracer) -> Option<usize> {
+    let mut boards = inputs.boards.to_vec();
+    let mut len = boards.len();
+
+    for &n in &inputs.draw {
+        tracer.trace(|| Event::Draw { number: n });
+        for (i, b) in boards.iter_mut().enumerate().filter(|(_, b)| !b.winner) {
+            if b.set_num(n) {
+                tracer.trace(|| Event::Win {
+                    board: i,
+                    score: b.score * n,
+                });
+                len -= 1;
+
+                if len == 0 {
+                    return Some(b.score * n);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+
+    type Parsed = Bingo;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Option<usize> {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<usize> {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let bingo = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&bingo).unwrap();
+
+        assert_eq!(serde_json::from_str::<Bingo>(&json).unwrap(), bingo);
+
+        for (from, to) in [
+            (r#""draw":[7,"#, r#""draw":[100,"#),
+            (r#""row":[[22,"#, r#""row":[[122,"#),
+            (r#""row":[[22,"#, r#""row":[[13,"#),
+            (r#""score":300"#, r#""score":301"#),
+        ] {
+            let json = json.replacen(from, to, 1);
+            assert!(serde_json::from_str::<Bingo>(&json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    pub fn test_invalid() {
+        let input = SAMPLE.replacen("23  4 24", "23  x 24", 1);
+        let err = generator(&input).unwrap_err();
+
+        assert_eq!((err.day, err.line, err.column), (4, 4, 11));
+        assert_eq!(err.text, "x");
+        let input = SAMPLE.replacen("22 13 17 11  0", "22 13 17 11 22", 1);
+        let err = generator(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 13));
+        assert_eq!(err.reason, "number is already on the board");
+        assert!(generator("7,4,9").is_err());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), Some(4512));
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), Some(1924));
+    }
+
+    #[test]
+    pub fn test_never_wins() {
+        // Only numbers up to 26 are drawn, so a board of bigger ones never wins
+        let late = (50..75).map(|n| n.to_string()).collect::<Vec<_>>();
+        let late = late.chunks(5).map(|row| row.join(" ")).collect::<Vec<_>>();
+        let bingo = generator(&format!("{SAMPLE}\n\n{}", late.join("\n"))).unwrap();
+        assert_eq!((part1(&bingo), part2(&bingo)), (Some(4512), None));
+
+        let (_, boards) = SAMPLE.split_once('\n').unwrap();
+        let bingo = generator(&format!("7,4,9\n{boards}")).unwrap();
+        assert_eq!((part1(&bingo), part2(&bingo)), (None, None));
+    }
+
+    #[test]
+    pub fn test_trace() {
+        let mut events = Vec::new();
+        part1_traced(&generator(SAMPLE).unwrap(), &mut events);
+
+        assert_eq!(events.len(), 13);
+        assert_eq!(events[0], Event::Draw { number: 7 });
+        assert_eq!(
+            events.last(),
+            Some(&Event::Win {
+                board: 2,
+                score: 4512
+            })
+        );
+    }
+}
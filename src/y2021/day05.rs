@@ -0,0 +1,305 @@
+use std::io::BufRead;
+
+use crate::utils::parse_pair;
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+    stream::{StreamError, for_each_line},
+};
+
+pub(crate) const SIZE: usize = 1000;
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawLine"))]
+pub struct Line {
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+// A line as serialized, checked like a parsed one since its points index the map
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawLine {
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawLine> for Line {
+    type Error = String;
+
+    fn try_from(raw: RawLine) -> Result<Self, Self::Error> {
+        if !in_bounds(raw.start) || !in_bounds(raw.end) {
+            return Err(format!("coordinates must be below {SIZE}"));
+        }
+
+        Line::new(raw.start, raw.end)
+            .ok_or_else(|| "expected a horizontal, vertical or 45 degree line".to_string())
+    }
+}
+
+impl Line {
+    fn parse(src: &Source, s: &str) -> Result<Self, ParseError> {
+        let (start, end) = src.expect(s.split_once(" -> "), s, "expected `x1,y1 -> x2,y2`")?;
+        let start = parse_point(src, start)?;
+        let end = parse_point(src, end)?;
+
+        src.expect(
+            Line::new(start, end),
+            s,
+            "expected a horizontal, vertical or 45 degree line",
+        )
+    }
+
+    // `None` unless the line is horizontal, vertical or diagonal, the only ones the map can draw
+    fn new(start: (usize, usize), end: (usize, usize)) -> Option<Self> {
+        let line = Line { start, end };
+
+        (line.for_part1() || start.0.abs_diff(end.0) == start.1.abs_diff(end.1)).then_some(line)
+    }
+
+    fn for_part1(&self) -> bool {
+        self.start.0 == self.end.0 || self.start.1 == self.end.1
+    }
+
+    fn points_part1(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let startr = self.start.0.min(self.end.0);
+        let stopr = self.start.0.max(self.end.0);
+
+        let startc = self.start.1.min(self.end.1);
+        let stopc = self.start.1.max(self.end.1);
+
+        (startr..=stopr).cartesian_product(startc..=stopc)
+    }
+
+    fn points_part2(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let inc = |x: &mut usize| *x += 1;
+        let dec = |x: &mut usize| *x -= 1;
+
+        let r_inc = if self.start.0 < self.end.0 { inc } else { dec };
+        let c_inc = if self.start.1 < self.end.1 { inc } else { dec };
+        let (mut r, mut c) = self.start;
+
+        std::iter::from_fn(move || {
+            let res = (r, c);
+
+            if res == self.end {
+                None
+            } else {
+                r_inc(&mut r);
+                c_inc(&mut c);
+                Some(res)
+            }
+        })
+        .chain(std::iter::once(self.end))
+    }
+}
+
+#[aoc_generator(day5)]
+pub fn generator(input: &str) -> Result<Vec<Line>, ParseError> {
+    let src = Source::new(5, input);
+
+    input.lines().map(|x| Line::parse(&src, x)).collect()
+}
+
+fn parse_point(src: &Source, s: &str) -> Result<(usize, usize), ParseError> {
+    let point = src.expect(parse_pair(s), s, "expected `x,y`")?;
+    if in_bounds(point) {
+        Ok(point)
+    } else {
+        Err(src.error(s, format!("coordinates must be below {SIZE}")))
+    }
+}
+
+fn in_bounds((x, y): (usize, usize)) -> bool {
+    x < SIZE && y < SIZE
+}
+
+// Counts the point as overlapping the first time a second line crosses it
+fn mark(map: &mut [[u8; SIZE]], points: impl Iterator<Item = (usize, usize)>, count: &mut usize) {
+    for coord in points {
+        let cell = &mut map[coord.0][coord.1];
+        *cell = cell.saturating_add(1);
+        if *cell == 2 {
+            *count += 1;
+        }
+    }
+}
+
+fn solve(inputs: &[Line]) -> (Vec<[u8; SIZE]>, usize) {
+    let mut map = vec![[0; SIZE]; SIZE];
+    let mut count = 0;
+
+    for l in inputs.iter().filter(|x| x.for_part1()) {
+        mark(&mut map, l.points_part1(), &mut count);
+    }
+
+    (map, count)
+}
+
+#[aoc(day5, part1)]
+pub fn part1(inputs: &[Line]) -> usize {
+    solve(inputs).1
+}
+
+#[aoc(day5, part2)]
+pub fn part2(inputs: &[Line]) -> usize {
+    let (mut map, mut count) = solve(inputs);
+
+    for l in inputs.iter().filter(|x| !x.for_part1()) {
+        mark(&mut map, l.points_part2(), &mut count);
+    }
+
+    count
+}
+
+/// Counts both parts' overlapping points while reading, memory is the two fixed size maps however
+/// many lines there are.
+pub fn stream<R: BufRead>(reader: R) -> Result<(usize, usize), StreamError> {
+    // Part 2 sees every line, part 1 only the horizontal and vertical ones
+    let mut straight = vec![[0; SIZE]; SIZE];
+    let mut all = vec![[0; SIZE]; SIZE];
+    let (mut part1, mut part2) = (0, 0);
+
+    for_each_line(5, reader, |src, s| {
+        let l = Line::parse(src, s)?;
+        if l.for_part1() {
+            mark(&mut straight, l.points_part1(), &mut part1);
+            mark(&mut all, l.points_part1(), &mut part2);
+        } else {
+            mark(&mut all, l.points_part2(), &mut part2);
+        }
+
+        Ok(())
+    })?;
+
+    Ok((part1, part2))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
+
+    type Parsed = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let lines = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&lines).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Line>>(&json).unwrap(), lines);
+
+        for json in [
+            r#"{"start":[0,1000],"end":[0,0]}"#,
+            r#"{"start":[0,0],"end":[1,2]}"#,
+        ] {
+            assert!(serde_json::from_str::<Line>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    pub fn test_diagonal() {
+        fn sorted_equal<T>(mut a: Vec<T>, mut b: Vec<T>)
+        where
+            T: Ord + std::fmt::Debug,
+        {
+            a.sort_unstable();
+            b.sort_unstable();
+
+            assert_eq!(a, b)
+        }
+
+        let t = Line {
+            start: (1, 1),
+            end: (3, 3),
+        };
+
+        sorted_equal(
+            t.points_part2().collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2), (3, 3)],
+        );
+
+        let t = Line {
+            start: (9, 7),
+            end: (7, 9),
+        };
+        sorted_equal(
+            t.points_part2().collect::<Vec<_>>(),
+            vec![(9, 7), (8, 8), (7, 9)],
+        );
+        let err = generator("0,0 -> 2,1").unwrap_err();
+        assert_eq!(
+            err.reason,
+            "expected a horizontal, vertical or 45 degree line"
+        );
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 5);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 12);
+    }
+
+    #[test]
+    pub fn test_stream() {
+        assert_eq!(stream(SAMPLE.as_bytes()).unwrap(), (5, 12));
+
+        let input = crate::r#gen::find(crate::y2021::YEAR, 5)
+            .unwrap()
+            .generate(0, 50);
+        let parsed = generator(&input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes()).unwrap(),
+            (part1(&parsed), part2(&parsed))
+        );
+
+        // More lines through one point than a cell can count
+        let input = "0,0 -> 0,1\n".repeat(300);
+        assert_eq!(stream(input.as_bytes()).unwrap(), (2, 2));
+    }
+}
@@ -0,0 +1,89 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+fn solve<const N: usize>(mut fish: [usize; 9]) -> usize {
+    for _ in 0..N / 9 {
+        for x in 0..9 {
+            fish[(x + 7) % 9] += fish[x % 9];
+        }
+    }
+
+    for x in 0..N % 9 {
+        fish[(x + 7) % 9] += fish[x % 9];
+    }
+
+    fish.iter().sum()
+}
+
+#[aoc_generator(day6)]
+pub fn generator(input: &str) -> Result<[usize; 9], ParseError> {
+    let src = Source::new(6, input);
+
+    input.split(',').try_fold([0; 9], |mut a, x| {
+        let n: usize = src.parse(x)?;
+        *a.get_mut(n)
+            .ok_or_else(|| src.error(x, "timer must be between 0 and 8"))? += 1;
+        Ok(a)
+    })
+}
+
+#[aoc(day6, part1)]
+pub fn part1(inputs: &[usize; 9]) -> usize {
+    solve::<80>(*inputs)
+}
+
+#[aoc(day6, part2)]
+pub fn part2(inputs: &[usize; 9]) -> usize {
+    solve::<256>(*inputs)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+
+    type Parsed = [usize; 9];
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"3,4,3,1,2";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        assert_eq!(generator(SAMPLE).unwrap(), [0, 1, 1, 2, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 5934);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 26984457539);
+    }
+}
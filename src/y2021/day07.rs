@@ -0,0 +1,107 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+const TRI: [usize; 2048] = precalculate_triangle_seq();
+
+const fn precalculate_triangle_seq<const N: usize>() -> [usize; N] {
+    let mut res = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        res[i] = i * (i + 1) / 2;
+        i += 1;
+    }
+
+    res
+}
+
+#[aoc_generator(day7)]
+pub fn generator(input: &str) -> Result<Vec<usize>, ParseError> {
+    let src = Source::new(7, input);
+    let mut v = input
+        .split(',')
+        .map(|x| match src.parse(x)? {
+            n if n < TRI.len() => Ok(n),
+            _ => Err(src.error(x, format!("positions must be below {}", TRI.len()))),
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    v.sort_unstable();
+    Ok(v)
+}
+
+#[aoc(day7, part1)]
+pub fn part1(inputs: &[usize]) -> usize {
+    let median = inputs[inputs.len() / 2];
+
+    inputs.iter().map(|&x| x.abs_diff(median)).sum()
+}
+
+#[aoc(day7, part2)]
+pub fn part2(inputs: &[usize]) -> usize {
+    let mean: usize = inputs.iter().sum::<usize>() / inputs.len();
+
+    (mean..=(mean + 1))
+        .map(|n| inputs.iter().map(|&x| TRI[x.abs_diff(n)]).sum())
+        .min()
+        .unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 37);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 168);
+    }
+
+    #[test]
+    pub fn test_range() {
+        assert_eq!(part2(&generator("2047,0").unwrap()), 1024 * 1024);
+        assert_eq!(
+            generator("1,2048").unwrap_err().reason,
+            "positions must be below 2048"
+        );
+    }
+}
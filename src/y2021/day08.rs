@@ -0,0 +1,256 @@
+use std::{ops::Range, str::FromStr};
+
+use ahash::AHashMap as HashMap;
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::utils::build_array;
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawObject"))]
+pub struct Object {
+    before: [(u8, u8); 10],
+    after: [(u8, u8); 4],
+}
+
+// An entry as serialized, every digit is checked like a parsed one since the solver picks its
+// candidates by segment count
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawObject {
+    before: [(u8, u8); 10],
+    after: [(u8, u8); 4],
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawObject> for Object {
+    type Error = &'static str;
+
+    fn try_from(raw: RawObject) -> Result<Self, Self::Error> {
+        let valid = |&(segments, count): &(u8, u8)| {
+            segments < 1 << 7
+                && (2..=7).contains(&count)
+                && segments.count_ones() == u32::from(count)
+        };
+        if !raw.before.iter().chain(&raw.after).all(valid) {
+            return Err("expected digits of 2 to 7 segments from a to g");
+        }
+
+        Ok(Object::new(raw.before, raw.after))
+    }
+}
+
+impl Object {
+    fn parse(src: &Source, s: &str) -> Result<Self, ParseError> {
+        let (a, b) = src.expect(s.split_once(" | "), s, "unable to find |")?;
+
+        let before = build_array(parse_digits::<10>(src, a)?);
+        let after = build_array(parse_digits::<4>(src, b)?);
+
+        Ok(Object::new(before, after))
+    }
+
+    fn new(mut before: [(u8, u8); 10], after: [(u8, u8); 4]) -> Self {
+        before.sort_unstable_by_key(|f| f.1);
+
+        Object { before, after }
+    }
+}
+
+impl FromStr for Object {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&Source::new(8, s), s)
+    }
+}
+
+fn parse_digits<const N: usize>(src: &Source, s: &str) -> Result<Vec<(u8, u8)>, ParseError> {
+    let digits = s
+        .split(' ')
+        .map(|d| {
+            if !(2..=7).contains(&d.len()) || !d.bytes().all(|c| (b'a'..=b'g').contains(&c)) {
+                Err(src.error(d, "expected 2 to 7 segments from a to g"))
+            } else {
+                Ok(pack_digit(d))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() == N {
+        Ok(digits)
+    } else {
+        Err(src.error(s, format!("expected {N} digits")))
+    }
+}
+
+fn pack_digit(s: &str) -> (u8, u8) {
+    s.bytes().fold((0, 0), |(tot, count), c| {
+        (tot | (1 << (c - b'a')), count + 1)
+    })
+}
+
+fn get_range(len: u8) -> Range<usize> {
+    // constraints are ordered
+    // [2, 3, 4, 5, 5, 5, 6, 6, 6, 7]
+    match len {
+        2 => 0..1,
+        3 => 1..2,
+        4 => 2..3,
+        5 => 3..6,
+        6 => 6..9,
+        7 => 9..10,
+        _ => unreachable!(),
+    }
+}
+
+// `None` if the input is wired in a way no display could be
+fn find(constraints: [(u8, u8); 10], len: u8, predicate: impl Fn(u8) -> bool) -> Option<u8> {
+    constraints[get_range(len)]
+        .iter()
+        .find(|x| predicate(x.0))
+        .map(|x| x.0)
+}
+
+fn analyze(constraints: [(u8, u8); 10]) -> Option<HashMap<u8, usize>> {
+    //  aaaa      2 => [1]
+    // b    c     3 => [7]
+    // b    c     4 => [4]
+    //  dddd      5 => [2, 3, 5]
+    // e    f     6 => [0, 6, 9]
+    // e    f     7 => [8]
+    //  gggg
+
+    let mut res = [0; 10];
+
+    // These are easy, 1, 4, 7, 8 all have unique number of segments
+    res[1] = find(constraints, 2, |_| true)?;
+    res[4] = find(constraints, 4, |_| true)?;
+    res[7] = find(constraints, 3, |_| true)?;
+    res[8] = find(constraints, 7, |_| true)?;
+
+    // Of the possible 5 segment numbers, only 3 share the same segments as 1
+    res[3] = find(constraints, 5, |x| x & res[1] == res[1])?;
+
+    // Of the possible 6 segment numbers, only 6 doesn't share all the segments as 1
+    res[6] = find(constraints, 6, |x| x & res[1] != res[1])?;
+
+    // Of the possible 6 segment numbers, only 9 share the same segments as 3
+    res[9] = find(constraints, 6, |x| x & res[3] == res[3])?;
+
+    // Of the possible 5 segment numbers, only 5 is a subset of 6
+    res[5] = find(constraints, 5, |x| x & res[6] == x)?;
+
+    // Find 2 and 0 by process of elminination
+    res[2] = find(constraints, 5, |x| x != res[3] && x != res[5])?;
+    res[0] = find(constraints, 6, |x| x != res[6] && x != res[9])?;
+
+    Some(res.into_iter().enumerate().map(|(a, b)| (b, a)).collect())
+}
+
+#[aoc_generator(day8)]
+pub fn generator(input: &str) -> Result<Vec<Object>, ParseError> {
+    let src = Source::new(8, input);
+    input.lines().map(|l| Object::parse(&src, l)).collect()
+}
+
+#[aoc(day8, part1)]
+pub fn part1(inputs: &[Object]) -> usize {
+    inputs
+        .iter()
+        .flat_map(|l| l.after.iter())
+        .filter(|x| matches!(x.1, 2 | 3 | 4 | 7))
+        // .inspect(|w| println!("**{}", w))
+        .count()
+}
+
+#[aoc(day8, part2)]
+pub fn part2(inputs: &[Object]) -> Option<usize> {
+    inputs
+        .iter()
+        .map(|line| {
+            let perm = analyze(line.before)?;
+            line.after
+                .iter()
+                .try_fold(0, |acc, digit| Some(acc * 10 + perm.get(&digit.0)?))
+        })
+        .sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 8;
+
+    type Parsed = Vec<Object>;
+    type Answer1 = usize;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<usize> {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let entries = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&entries).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Object>>(&json).unwrap(), entries);
+
+        // One segment, which no digit has, and a count that doesn't match the segments
+        let json = serde_json::to_string(&entries[0]).unwrap();
+        for (from, to) in [("[[18,2]", "[[1,1]"), ("[[18,2]", "[[18,3]")] {
+            let json = json.replacen(from, to, 1);
+            assert!(serde_json::from_str::<Object>(&json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 26);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), Some(61229));
+
+        let miswired = "ab abc abcd abcde abcde abcde abcdef abcdef abcdef abcdefg | ab ab ab ab";
+        assert_eq!(part2(&generator(miswired).unwrap()), None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::cmp::Reverse;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    grid::{Grid, Pos},
+    solution::Solution,
+};
+
+#[aoc_generator(day9)]
+pub fn generator(input: &str) -> Result<Grid<u8>, ParseError> {
+    let src = Source::new(9, input);
+
+    Grid::parse(
+        &src,
+        input,
+        |c| c.to_digit(10).map(|d| d as u8),
+        "expected a digit",
+    )
+}
+
+#[aoc(day9, part1)]
+pub fn part1(inputs: &Grid<u8>) -> usize {
+    inputs
+        .positions()
+        .filter_map(|p| {
+            let cell = inputs[p];
+            if inputs.neighbors(p).all(|n| inputs[n] > cell) {
+                Some(usize::from(cell) + 1)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+#[aoc(day9, part2)]
+pub fn part2(inputs: &Grid<u8>) -> usize {
+    let mut v = inputs.clone();
+    let mut stack = Vec::new();
+    let mut areas: Vec<_> = inputs
+        .positions()
+        .map(|p| find_basin(&mut v, &mut stack, p))
+        .collect();
+
+    // Fewer than three basins only multiplies the ones there are
+    areas.sort_unstable_by_key(|&x| Reverse(x));
+    areas.iter().take(3).product()
+}
+
+// Flood fills with a stack rather than recursion, one huge basin can't overflow the call stack
+fn find_basin(v: &mut Grid<u8>, stack: &mut Vec<Pos>, p: Pos) -> usize {
+    stack.push(p);
+    let mut area = 0;
+
+    while let Some(p) = stack.pop() {
+        if v[p] > 8 {
+            continue;
+        }
+
+        v[p] = 10;
+        area += 1;
+        stack.extend(v.neighbors(p));
+    }
+
+    area
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 9;
+
+    type Parsed = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 15);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 1134);
+        assert_eq!(part2(&generator("1").unwrap()), 1);
+    }
+}
@@ -0,0 +1,255 @@
+use std::io::BufRead;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+    stream::{StreamError, for_each_line},
+};
+
+#[aoc_generator(day10)]
+pub fn generator(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let src = Source::new(10, input);
+
+    input
+        .lines()
+        .map(|line| parse_line(&src, line).map(|l| l.to_vec()))
+        .collect()
+}
+
+fn parse_line<'a>(src: &Source, line: &'a str) -> Result<&'a [u8], ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+        return Err(src.error(&line[i..i + c.len_utf8()], "expected a bracket"));
+    }
+
+    // Part 2 needs every completion score to fit
+    if let Ok(stack) = process(line.as_bytes())
+        && completion_score(&stack).is_none()
+    {
+        return Err(src.error(line, "too many unclosed chunks"));
+    }
+
+    Ok(line.as_bytes())
+}
+
+fn get_match(bracket: u8) -> Option<u8> {
+    match bracket {
+        b'(' => Some(b')'),
+        b'{' => Some(b'}'),
+        b'[' => Some(b']'),
+        b'<' => Some(b'>'),
+        _ => None,
+    }
+}
+
+fn process(line: &[u8]) -> Result<Vec<u8>, u8> {
+    let mut stack = Vec::new();
+
+    for &bracket in line.iter() {
+        if let Some(right) = get_match(bracket) {
+            stack.push(right);
+        } else if Some(bracket) != stack.pop() {
+            return Err(bracket);
+        }
+    }
+
+    Ok(stack)
+}
+
+#[aoc(day10, part1)]
+pub fn part1(inputs: &[Vec<u8>]) -> usize {
+    inputs
+        .iter()
+        .map(|line| {
+            if let Err(e) = process(line) {
+                score1(e)
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn score1(c: u8) -> usize {
+    match c {
+        b')' => 3,
+        b']' => 57,
+        b'}' => 1197,
+        b'>' => 25137,
+        _ => 0,
+    }
+}
+
+fn score2(c: u8) -> usize {
+    match c {
+        b')' => 1,
+        b']' => 2,
+        b'}' => 3,
+        b'>' => 4,
+        _ => 0,
+    }
+}
+
+/// The median completion score, `None` when no line is incomplete.
+#[aoc(day10, part2)]
+pub fn part2(inputs: &[Vec<u8>]) -> Option<usize> {
+    let ans = inputs
+        .iter()
+        .filter_map(|line| incomplete(line).and_then(|stack| completion_score(&stack)))
+        .sorted_unstable()
+        .collect_vec();
+
+    ans.get(ans.len() / 2).copied()
+}
+
+// The brackets left to close, `None` for a corrupted or complete line
+fn incomplete(line: &[u8]) -> Option<Vec<u8>> {
+    process(line).ok().filter(|stack| !stack.is_empty())
+}
+
+// `None` if the score doesn't fit, which the generator rejects
+fn completion_score(stack: &[u8]) -> Option<usize> {
+    stack
+        .iter()
+        .rev()
+        .map(|&x| score2(x))
+        .try_fold(0usize, |acc, x| acc.checked_mul(5)?.checked_add(x))
+}
+
+/// Scores both parts while reading. Only the completion scores are kept, one per incomplete line,
+/// since part 2 needs their median; it is `None` when no line is incomplete.
+pub fn stream<R: BufRead>(reader: R) -> Result<(usize, Option<usize>), StreamError> {
+    let mut part1 = 0;
+    let mut completions = Vec::new();
+
+    for_each_line(10, reader, |src, line| {
+        match process(parse_line(src, line)?) {
+            Ok(stack) if stack.is_empty() => {}
+            Ok(stack) => completions.extend(completion_score(&stack)),
+            Err(c) => part1 += score1(c),
+        }
+
+        Ok(())
+    })?;
+
+    let part2 = (!completions.is_empty()).then(|| {
+        let middle = completions.len() / 2;
+        *completions.select_nth_unstable(middle).1
+    });
+
+    Ok((part1, part2))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<Vec<u8>>;
+    type Answer1 = usize;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<usize> {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 26397);
+    }
+
+    #[test]
+    pub fn test_lines_part1() {
+        let input = generator(SAMPLE).unwrap();
+        for &(index, expected) in [(2, b'}'), (4, b')'), (5, b']'), (7, b')'), (8, b'>')].iter() {
+            assert_eq!(process(&input[index]).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    pub fn test_lines_part2() {
+        let input = generator(SAMPLE).unwrap();
+
+        for &(index, expected) in [
+            (0, "}}]])})]"),
+            (1, ")}>]})"),
+            (3, "}}>}>))))"),
+            (6, "]]}}]}]}>"),
+            (9, "])}>"),
+        ]
+        .iter()
+        {
+            let mut ans = process(&input[index]).unwrap();
+            ans.reverse();
+
+            let expected = expected.bytes().collect_vec();
+            assert_eq!(ans, expected);
+        }
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), Some(288957));
+        assert_eq!(part2(&generator("()").unwrap()), None);
+
+        // 5^27 - 1 is the biggest score that fits
+        assert_eq!(
+            part2(&generator(&"<".repeat(27)).unwrap()),
+            Some(5usize.pow(27) - 1)
+        );
+        assert_eq!(
+            generator(&"<".repeat(28)).unwrap_err().reason,
+            "too many unclosed chunks"
+        );
+    }
+
+    #[test]
+    pub fn test_stream() {
+        assert_eq!(stream(SAMPLE.as_bytes()).unwrap(), (26397, Some(288957)));
+
+        let input = crate::r#gen::find(crate::y2021::YEAR, 10)
+            .unwrap()
+            .generate(0, 50);
+        let parsed = generator(&input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes()).unwrap(),
+            (part1(&parsed), part2(&parsed))
+        );
+        assert_eq!(stream("(]".as_bytes()).unwrap(), (57, None));
+        assert_eq!(stream("()".as_bytes()).unwrap(), (0, None));
+    }
+}
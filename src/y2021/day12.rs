@@ -4,6 +4,11 @@ use aoc_runner_derive::{aoc, aoc_generator};
 use nohash_hasher::IntMap;
 use once_cell::sync::Lazy;
 
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
 // lazy_static! {
 //     static ref START: Cave = Cave::new("start");
 //     static ref END: Cave = Cave::new("end");
@@ -17,6 +22,7 @@ fn is_little(s: &str) -> bool {
 }
 
 #[derive(Debug, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cave {
     hash: u64,
     little: bool,
@@ -56,17 +62,38 @@ fn make_hashmap<K, V>() -> HashMap<K, V> {
 }
 
 #[aoc_generator(day12)]
-pub fn generator(input: &str) -> HashMap<Cave, Vec<Cave>> {
-    input
+pub fn generator(input: &str) -> Result<HashMap<Cave, Vec<Cave>>, ParseError> {
+    let src = Source::new(12, input);
+
+    let graph = input
         .lines()
-        .map(|l| l.split_once('-').unwrap())
-        .fold(make_hashmap(), |mut hm, (a, b)| {
+        .try_fold(make_hashmap::<Cave, Vec<Cave>>(), |mut hm, l| {
+            let (a, b) = src.expect(l.split_once('-'), l, "expected `cave-cave`")?;
+            for name in [a, b] {
+                if name.is_empty() || !name.bytes().all(|c| c.is_ascii_alphabetic()) {
+                    return Err(src.error(name, "invalid cave name"));
+                }
+            }
+
             let x = Cave::new(a);
             let y = Cave::new(b);
+            if !x.little && !y.little {
+                return Err(src.error(
+                    l,
+                    "big caves can't be connected, paths through them never end",
+                ));
+            }
+
             hm.entry(x).or_default().push(y);
             hm.entry(y).or_default().push(x);
-            hm
-        })
+            Ok(hm)
+        })?;
+
+    if graph.contains_key(&*START) && graph.contains_key(&*END) {
+        Ok(graph)
+    } else {
+        Err(src.error(input, "expected both `start` and `end` caves"))
+    }
 }
 
 fn search(
@@ -109,6 +136,29 @@ pub fn part2(inputs: &HashMap<Cave, Vec<Cave>>) -> usize {
     search(inputs, &mut visited, *START, true)
 }
 
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 12;
+
+    type Parsed = HashMap<Cave, Vec<Cave>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,38 +203,30 @@ start-RW";
 
     #[test]
     pub fn test_input() {
-        println!("{:?}", generator(SAMPLE));
+        println!("{:?}", generator(SAMPLE).unwrap());
 
         // assert_eq!(generator(SAMPLE), Object());
     }
 
     #[test]
-    pub fn test1() {
-        assert_eq!(part1(&generator(SAMPLE)), 10);
-        assert_eq!(part1(&generator(SAMPLE2)), 19);
-        assert_eq!(part1(&generator(SAMPLE3)), 226);
+    pub fn test_invalid() {
+        let err = generator("start-A\nA-BB\nBB-end").unwrap_err();
+
+        assert_eq!((err.line, err.text.as_str()), (2, "A-BB"));
+        assert!(generator("start-a").is_err());
     }
 
     #[test]
-    pub fn test2() {
-        assert_eq!(part2(&generator(SAMPLE)), 36);
-        assert_eq!(part2(&generator(SAMPLE2)), 103);
-        assert_eq!(part2(&generator(SAMPLE3)), 3509);
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 10);
+        assert_eq!(part1(&generator(SAMPLE2).unwrap()), 19);
+        assert_eq!(part1(&generator(SAMPLE3).unwrap()), 226);
     }
 
-    mod regression {
-        use super::*;
-
-        const INPUT: &str = include_str!("../input/2021/day12.txt");
-        const ANSWERS: (usize, usize) = (4912, 150004);
-
-        #[test]
-        pub fn test() {
-            let input = INPUT.trim_end_matches('\n');
-            let output = generator(input);
-
-            assert_eq!(part1(&output), ANSWERS.0);
-            assert_eq!(part2(&output), ANSWERS.1);
-        }
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 36);
+        assert_eq!(part2(&generator(SAMPLE2).unwrap()), 103);
+        assert_eq!(part2(&generator(SAMPLE3).unwrap()), 3509);
     }
 }
@@ -0,0 +1,252 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+const SIZE: usize = 26;
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDay14"))]
+pub struct Day14 {
+    start: String,
+    rules: Vec<(u8, u8, u8)>,
+}
+
+// The polymer as serialized, checked like a parsed one since its elements index the pair counts
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawDay14 {
+    start: String,
+    rules: Vec<(u8, u8, u8)>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDay14> for Day14 {
+    type Error = &'static str;
+
+    fn try_from(RawDay14 { start, rules }: RawDay14) -> Result<Self, Self::Error> {
+        let mut elements = rules.iter().flat_map(|&(a, b, c)| [a, b, c]);
+        if !is_elements(start.as_bytes()) || !elements.all(is_element) {
+            return Err("expected uppercase letters");
+        }
+
+        let mut seen = [false; SIZE * SIZE];
+        if rules
+            .iter()
+            .any(|&(a, b, _)| std::mem::replace(&mut seen[encode(a, b)], true))
+        {
+            return Err("pair already has a rule");
+        }
+
+        Ok(Day14 { start, rules })
+    }
+}
+
+fn ord(a: u8) -> usize {
+    usize::from(a - b'A')
+}
+
+fn encode(a: u8, b: u8) -> usize {
+    ord(a) * SIZE + ord(b)
+}
+
+#[aoc_generator(day14)]
+pub fn generator(input: &str) -> Result<Day14, ParseError> {
+    let src = Source::new(14, input);
+    let (part1, part2) = src.expect(
+        input.split_once("\n\n"),
+        input,
+        "expected template and rules separated by a blank line",
+    )?;
+
+    let start = parse_elements(&src, part1)?.to_string();
+    // A pair with several rules would multiply instead of doubling every step
+    let mut seen = [false; SIZE * SIZE];
+    let rules = part2
+        .lines()
+        .map(|l| {
+            let (a, b) = src.expect(l.split_once(" -> "), l, "expected `AB -> C`")?;
+            let a = parse_elements(&src, a)?.as_bytes();
+            let b = parse_elements(&src, b)?.as_bytes();
+            match (a, b) {
+                (&[a0, a1], &[_]) if seen[encode(a0, a1)] => {
+                    Err(src.error(l, "pair already has a rule"))
+                }
+                (&[a0, a1], &[b]) => {
+                    seen[encode(a0, a1)] = true;
+                    Ok((a0, a1, b))
+                }
+                _ => Err(src.error(l, "expected `AB -> C`")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Day14 { start, rules })
+}
+
+// Elements are indexed by their offset from `A`, so only uppercase letters are allowed
+fn is_element(c: u8) -> bool {
+    c.is_ascii_uppercase()
+}
+
+fn is_elements(s: &[u8]) -> bool {
+    !s.is_empty() && s.iter().copied().all(is_element)
+}
+
+fn parse_elements<'a>(src: &Source, s: &'a str) -> Result<&'a str, ParseError> {
+    if is_elements(s.as_bytes()) {
+        Ok(s)
+    } else {
+        Err(src.error(s, "expected uppercase letters"))
+    }
+}
+
+fn generate_count(input: &str) -> [usize; SIZE * SIZE] {
+    input
+        .as_bytes()
+        .windows(2)
+        .fold([0; SIZE * SIZE], |mut count, w| {
+            count[encode(w[0], w[1])] += 1;
+            count
+        })
+}
+
+fn solve<const N: usize>(inputs: &Day14) -> usize {
+    let mut s = generate_count(&inputs.start);
+
+    for _ in 0..N {
+        let mut new_s = [0; SIZE * SIZE];
+
+        for &(a, b, r#gen) in inputs.rules.iter() {
+            let count = s[encode(a, b)];
+            let (left, right) = (encode(a, r#gen), encode(r#gen, b));
+            new_s[left] += count;
+            new_s[right] += count;
+        }
+        s = new_s;
+    }
+
+    let mut freq =
+        s.iter()
+            .enumerate()
+            .filter(|(_, x)| **x > 0)
+            .fold([0; SIZE], |mut hm, (k, &c)| {
+                hm[k % SIZE] += c;
+                hm
+            });
+
+    freq[ord(inputs.start.as_bytes()[0])] += 1;
+
+    let (&min, &max) = freq
+        .iter()
+        .filter(|x| **x > 0)
+        .minmax_by_key(|&count| count)
+        .into_option()
+        .unwrap();
+
+    max - min
+}
+
+#[aoc(day14, part1)]
+pub fn part1(inputs: &Day14) -> usize {
+    solve::<10>(inputs)
+}
+
+#[aoc(day14, part2)]
+pub fn part2(inputs: &Day14) -> usize {
+    solve::<40>(inputs)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 14;
+
+    type Parsed = Day14;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let polymer = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&polymer).unwrap();
+        assert_eq!(serde_json::from_str::<Day14>(&json).unwrap(), polymer);
+
+        for json in [
+            r#"{"start":"","rules":[]}"#,
+            r#"{"start":"NNcB","rules":[]}"#,
+            r#"{"start":"NNCB","rules":[[67,72,0]]}"#,
+            r#"{"start":"NNCB","rules":[[67,72,66],[67,72,67]]}"#,
+        ] {
+            assert!(serde_json::from_str::<Day14>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 1588);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 2188189693529);
+    }
+
+    #[test]
+    pub fn test_invalid() {
+        let err = generator("NN\n\nNN -> C\nNC -> B\nNN -> B").unwrap_err();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (5, "pair already has a rule")
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use pathfinding::prelude::dijkstra;
+
+use crate::{
+    error::{ParseError, Source},
+    grid::{Grid, Pos},
+    solution::Solution,
+    utils::neighbors,
+};
+
+#[aoc_generator(day15)]
+pub fn generator(input: &str) -> Result<Grid<usize>, ParseError> {
+    let src = Source::new(15, input);
+
+    Grid::parse(
+        &src,
+        input,
+        |c| c.to_digit(10).filter(|&d| d > 0).map(|d| d as usize),
+        "expected a digit from 1 to 9",
+    )
+}
+
+fn solve(get: impl Fn(Pos) -> usize, height: usize, width: usize) -> usize {
+    dijkstra(
+        &(0, 0),
+        |p| neighbors(p.0, p.1, height, width).map(|p| (p, get(p))),
+        //   |p| absdiff(height - 1, p.0) + absdiff(p.1, width - 1),
+        |p| *p == (height - 1, width - 1),
+    )
+    .unwrap()
+    .1
+}
+
+#[aoc(day15, part1)]
+pub fn part1(map: &Grid<usize>) -> usize {
+    solve(|p| map[p], map.height(), map.width())
+}
+
+#[aoc(day15, part2)]
+pub fn part2(map: &Grid<usize>) -> usize {
+    const M: usize = 5;
+    let h0 = map.height();
+    let w0 = map.width();
+
+    let get = |(r, c): Pos| -> usize {
+        let ans = map[(r % h0, c % w0)] + r / h0 + c / w0;
+        (ans - 1) % 9 + 1
+    };
+
+    solve(get, h0 * M, w0 * M)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 15;
+
+    type Parsed = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test_invalid() {
+        let err = generator("19\n90").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.reason, "expected a digit from 1 to 9");
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 40);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 315);
+    }
+}
@@ -0,0 +1,352 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawPacket"))]
+pub struct Packet {
+    version: usize,
+    contents: Contents,
+}
+
+// A packet as serialized, checked like a decoded one. Its sub-packets are checked as they're
+// deserialized, so only this packet's own fields and depth are left
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPacket {
+    version: usize,
+    contents: Contents,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPacket> for Packet {
+    type Error = &'static str;
+
+    fn try_from(RawPacket { version, contents }: RawPacket) -> Result<Self, Self::Error> {
+        if version >= 8 {
+            return Err("versions have 3 bits");
+        }
+
+        if let Contents::Operator { type_id, packets } = &contents {
+            if !takes(*type_id, packets.len()) {
+                return Err("operators take 1 or more sub-packets, comparisons 2");
+            }
+            if packets.iter().map(Packet::depth).max() >= Some(MAX_DEPTH) {
+                return Err("packets are nested too deep");
+            }
+        }
+
+        Ok(Packet { version, contents })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Contents {
+    Literal(usize),
+    Operator {
+        type_id: usize,
+        packets: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    // How many packets deep the innermost sub-packet is
+    #[cfg(feature = "serde")]
+    fn depth(&self) -> usize {
+        match &self.contents {
+            Contents::Literal(_) => 0,
+            Contents::Operator { packets, .. } => {
+                1 + packets.iter().map(Packet::depth).max().unwrap_or(0)
+            }
+        }
+    }
+
+    fn versions(&self) -> usize {
+        self.version
+            + match &self.contents {
+                Contents::Literal(_) => 0,
+                Contents::Operator { packets, .. } => packets.iter().map(Packet::versions).sum(),
+            }
+    }
+
+    // `None` if the sum or product overflows
+    fn value(&self) -> Option<usize> {
+        let (type_id, packets) = match &self.contents {
+            Contents::Literal(n) => return Some(*n),
+            Contents::Operator { type_id, packets } => (type_id, packets),
+        };
+        let mut values = packets.iter().map(Packet::value);
+
+        match type_id {
+            0 => values.try_fold(0_usize, |acc, v| acc.checked_add(v?)),
+            1 => values.try_fold(1_usize, |acc, v| acc.checked_mul(v?)),
+            2 => values.try_fold(usize::MAX, |acc, v| Some(acc.min(v?))),
+            3 => values.try_fold(0, |acc, v| Some(acc.max(v?))),
+            _ => {
+                let (a, b) = (values.next()??, values.next()??);
+                Some(usize::from(match type_id {
+                    5 => a > b,
+                    6 => a < b,
+                    _ => a == b,
+                }))
+            }
+        }
+    }
+}
+
+// Reads packets off the bits of the transmission, reporting errors at the hex digit they're in
+struct Decoder<'a> {
+    src: Source<'a>,
+    input: &'a str,
+    bits: Vec<u8>,
+    pc: usize,
+}
+
+// Deep enough for any puzzle input, shallow enough that recursing over the tree can't
+// overflow the stack
+const MAX_DEPTH: usize = 64;
+
+// Whether an operator of `type_id` can take `count` sub-packets
+fn takes(type_id: usize, count: usize) -> bool {
+    matches!((type_id, count), (5..=7, 2) | (0..=3, 1..))
+}
+
+impl Decoder<'_> {
+    fn error(&self, bit: usize, reason: &str) -> ParseError {
+        let i = (bit / 4).min(self.input.len().saturating_sub(1));
+
+        self.src
+            .error(&self.input[i..(i + 1).min(self.input.len())], reason)
+    }
+
+    fn read(&mut self, n: usize) -> Result<usize, ParseError> {
+        let bits = self
+            .bits
+            .get(self.pc..self.pc + n)
+            .ok_or_else(|| self.error(self.bits.len(), "transmission ends mid-packet"))?;
+        self.pc += n;
+
+        Ok(bits.iter().fold(0, |n, &b| n * 2 + usize::from(b)))
+    }
+
+    fn packet(&mut self, depth: usize) -> Result<Packet, ParseError> {
+        let start = self.pc;
+        if depth > MAX_DEPTH {
+            return Err(self.error(start, "packets are nested too deep"));
+        }
+
+        let version = self.read(3)?;
+        let type_id = self.read(3)?;
+
+        if type_id == 4 {
+            let mut value: usize = 0;
+            loop {
+                let last = self.read(1)? == 0;
+                value = value
+                    .checked_mul(16)
+                    .ok_or_else(|| self.error(self.pc, "literal is too big"))?
+                    + self.read(4)?;
+
+                if last {
+                    break;
+                }
+            }
+
+            return Ok(Packet {
+                version,
+                contents: Contents::Literal(value),
+            });
+        }
+
+        let mut packets = Vec::new();
+        if self.read(1)? == 0 {
+            let length = self.read(15)?;
+            let stop = self.pc + length;
+
+            while self.pc < stop {
+                packets.push(self.packet(depth + 1)?);
+            }
+            if self.pc != stop {
+                return Err(self.error(start, "sub-packets overrun their length"));
+            }
+        } else {
+            for _ in 0..self.read(11)? {
+                packets.push(self.packet(depth + 1)?);
+            }
+        }
+
+        if takes(type_id, packets.len()) {
+            Ok(Packet {
+                version,
+                contents: Contents::Operator { type_id, packets },
+            })
+        } else if (5..=7).contains(&type_id) {
+            Err(self.error(start, "expected 2 sub-packets"))
+        } else {
+            Err(self.error(start, "expected at least 1 sub-packet"))
+        }
+    }
+}
+
+#[aoc_generator(day16)]
+pub fn generator(input: &str) -> Result<Packet, ParseError> {
+    let src = Source::new(16, input);
+    let mut bits = Vec::new();
+
+    for (i, c) in input.char_indices() {
+        let n = src.expect(
+            c.to_digit(16),
+            &input[i..i + c.len_utf8()],
+            "expected a hexadecimal digit",
+        )? as u8;
+        bits.extend([(n >> 3) & 0b1, (n >> 2) & 0b1, (n >> 1) & 0b1, n & 0b1]);
+    }
+
+    // Whatever follows the outermost packet is padding
+    Decoder {
+        src,
+        input,
+        bits,
+        pc: 0,
+    }
+    .packet(0)
+}
+
+#[aoc(day16, part1)]
+pub fn part1(packet: &Packet) -> usize {
+    packet.versions()
+}
+
+#[aoc(day16, part2)]
+pub fn part2(packet: &Packet) -> Option<usize> {
+    packet.value()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 16;
+
+    type Parsed = Packet;
+    type Answer1 = usize;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<usize> {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"EE00D40C823060";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 14);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), Some(3));
+
+        for (input, expected) in [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ] {
+            assert_eq!(part2(&generator(input).unwrap()), Some(expected), "{input}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let packet = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&packet).unwrap();
+        assert_eq!(serde_json::from_str::<Packet>(&json).unwrap(), packet);
+
+        let literal = r#"{"version":1,"contents":{"Literal":5}}"#;
+        let operator = |type_id, packets: &[&str]| {
+            format!(
+                r#"{{"version":1,"contents":{{"Operator":{{"type_id":{type_id},"packets":[{}]}}}}}}"#,
+                packets.join(",")
+            )
+        };
+        assert!(serde_json::from_str::<Packet>(&operator(5, &[literal, literal])).is_ok());
+
+        for json in [
+            literal.replace("1", "8"),
+            operator(5, &[literal]),
+            operator(4, &[literal]),
+            operator(0, &[]),
+        ] {
+            assert!(serde_json::from_str::<Packet>(&json).is_err(), "{json}");
+        }
+
+        // serde_json stops well short of this depth by itself, other formats might not
+        let sum = |packet| RawPacket {
+            version: 0,
+            contents: Contents::Operator {
+                type_id: 0,
+                packets: vec![packet],
+            },
+        };
+        let deep = (1..MAX_DEPTH).try_fold(packet, |p, _| Packet::try_from(sum(p)));
+        assert!(Packet::try_from(sum(deep.unwrap())).is_err());
+    }
+
+    #[test]
+    pub fn test_invalid() {
+        // A literal cut off after its first group
+        let err = generator("D2FE").unwrap_err();
+        assert_eq!(
+            (err.column, err.reason.as_str()),
+            (4, "transmission ends mid-packet")
+        );
+
+        // Greater than with a single sub-packet
+        let err = generator("16004408").unwrap_err();
+        assert_eq!(
+            (err.column, err.reason.as_str()),
+            (1, "expected 2 sub-packets")
+        );
+
+        assert!(generator("").is_err());
+        assert_eq!(
+            generator(&"0".repeat(5000)).unwrap_err().reason,
+            "expected at least 1 sub-packet"
+        );
+        assert_eq!(
+            generator(&"02008".repeat(100)).unwrap_err().reason,
+            "packets are nested too deep"
+        );
+        assert!(generator(&format!("1F{}0", "F".repeat(20))).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::utils::parse_range;
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDay17"))]
+pub struct Day17 {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+// A target as serialized, checked like a parsed one since the search only covers `RANGE`
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawDay17 {
+    min_x: i32,
+    max_x: i32,
+    min_y: i32,
+    max_y: i32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDay17> for Day17 {
+    type Error = String;
+
+    fn try_from(raw: RawDay17) -> Result<Self, Self::Error> {
+        if !x_in_range(raw.min_x, raw.max_x) {
+            return Err(format!("expected `0 <= min_x <= max_x < {RANGE}`"));
+        }
+        if !y_in_range(raw.min_y, raw.max_y) {
+            return Err(format!("expected `-{RANGE} <= min_y <= max_y < 0`"));
+        }
+
+        Ok(Day17 {
+            min_x: raw.min_x,
+            max_x: raw.max_x,
+            min_y: raw.min_y,
+            max_y: raw.max_y,
+        })
+    }
+}
+
+const RANGE: i32 = 250;
+
+// The search only covers velocities that can reach a target in front of and below the launcher,
+// within `RANGE` of it
+fn x_in_range(min: i32, max: i32) -> bool {
+    0 <= min && min <= max && max < RANGE
+}
+
+fn y_in_range(min: i32, max: i32) -> bool {
+    -RANGE <= min && min <= max && max < 0
+}
+
+#[aoc_generator(day17)]
+pub fn generator(input: &str) -> Result<Day17, ParseError> {
+    let src = Source::new(17, input);
+    let (x, y) = src.expect(
+        input
+            .strip_prefix("target area: x=")
+            .and_then(|s| s.split_once(", y=")),
+        input,
+        "expected `target area: x=<min>..<max>, y=<min>..<max>`",
+    )?;
+    let (min_x, max_x) = src.expect(parse_range(x), x, "expected `<min>..<max>`")?;
+    let (min_y, max_y) = src.expect(parse_range(y), y, "expected `<min>..<max>`")?;
+
+    if !x_in_range(min_x, max_x) {
+        return Err(src.error(x, format!("expected `0 <= min <= max < {RANGE}`")));
+    }
+    if !y_in_range(min_y, max_y) {
+        return Err(src.error(y, format!("expected `-{RANGE} <= min <= max < 0`")));
+    }
+
+    Ok(Day17 {
+        min_x,
+        min_y,
+        max_x,
+        max_y,
+    })
+}
+
+struct Probe {
+    pos_x: i32,
+    pos_y: i32,
+    vol_x: i32,
+    vol_y: i32,
+}
+
+impl Probe {
+    fn new(vol_x: i32, vol_y: i32) -> Self {
+        Self {
+            pos_x: 0,
+            pos_y: 0,
+            vol_x,
+            vol_y,
+        }
+    }
+
+    fn simulate(&mut self, target: &Day17) -> Option<i32> {
+        let mut max = 0;
+
+        loop {
+            self.pos_x += self.vol_x;
+            self.pos_y += self.vol_y;
+
+            self.vol_x = 0.max(self.vol_x - 1);
+            self.vol_y -= 1;
+
+            max = max.max(self.pos_y);
+            if target.min_x <= self.pos_x
+                && self.pos_x <= target.max_x
+                && target.min_y <= self.pos_y
+                && self.pos_y <= target.max_y
+            {
+                return Some(max);
+            }
+
+            if self.vol_y < 0 && self.pos_y < target.min_y {
+                return None;
+            }
+        }
+    }
+}
+
+#[aoc(day17, part1)]
+pub fn part1(inputs: &Day17) -> Option<i32> {
+    let mut max = None;
+    for x in 0..RANGE {
+        for y in 0..RANGE {
+            if let Some(y) = Probe::new(x, y).simulate(inputs) {
+                max = max.max(Some(y));
+            }
+        }
+    }
+
+    max
+}
+
+#[aoc(day17, part2)]
+pub fn part2(inputs: &Day17) -> usize {
+    let mut count = 0;
+    for x in 0..RANGE {
+        for y in -RANGE..RANGE {
+            if Probe::new(x, y).simulate(inputs).is_some() {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 17;
+
+    type Parsed = Day17;
+    type Answer1 = Option<i32>;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Option<i32> {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"target area: x=20..30, y=-10..-5";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        assert_eq!(
+            generator(SAMPLE).unwrap(),
+            Day17 {
+                min_x: 20,
+                max_x: 30,
+                min_y: -10,
+                max_y: -5
+            }
+        );
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), Some(45));
+
+        // Too far to reach without shooting down
+        let target = generator("target area: x=200..200, y=-4..-4").unwrap();
+        assert_eq!(part1(&target), None);
+        assert_eq!(part2(&target), 1);
+    }
+
+    #[test]
+    pub fn test_range() {
+        for input in [
+            "target area: x=20..250, y=-10..-5",
+            "target area: x=30..20, y=-10..-5",
+            "target area: x=-30..-20, y=-10..-5",
+            "target area: x=20..30, y=-251..-5",
+            "target area: x=20..30, y=5..10",
+        ] {
+            assert!(generator(input).is_err(), "{input}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let target = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&target).unwrap();
+        assert_eq!(serde_json::from_str::<Day17>(&json).unwrap(), target);
+
+        for (from, to) in [("20", "-20"), ("-10", "10"), ("-5", "-500")] {
+            let json = json.replacen(from, to, 1);
+            assert!(serde_json::from_str::<Day17>(&json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 112);
+    }
+}
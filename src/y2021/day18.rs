@@ -0,0 +1,353 @@
+use std::str::FromStr;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    character::complete::{char, digit1},
+    combinator::{all_consuming, map, map_res},
+    sequence::{delimited, separated_pair},
+};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+    trace::{Event, NoTrace, Tracer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawSnail"))]
+pub enum Snail {
+    Num(usize),
+    Pair(Box<Snail>, Box<Snail>),
+}
+
+// A number as serialized, which has to be reduced like a parsed one. Its halves are checked as
+// they're deserialized, so only how deep they go is left
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+enum RawSnail {
+    Num(usize),
+    Pair(Box<Snail>, Box<Snail>),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawSnail> for Snail {
+    type Error = &'static str;
+
+    fn try_from(raw: RawSnail) -> Result<Self, Self::Error> {
+        match raw {
+            RawSnail::Num(n) if n > 9 => Err("expected a reduced snailfish number"),
+            RawSnail::Num(n) => Ok(Snail::Num(n)),
+            RawSnail::Pair(a, b) if a.depth().max(b.depth()) >= 4 => {
+                Err("expected a reduced snailfish number")
+            }
+            RawSnail::Pair(a, b) => Ok(Snail::Pair(a, b)),
+        }
+    }
+}
+
+impl From<usize> for Snail {
+    fn from(n: usize) -> Self {
+        Snail::Num(n)
+    }
+}
+
+impl Snail {
+    fn new<I: Into<Snail>>(left: I, right: I) -> Self {
+        Self::Pair(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    fn add_left(&mut self, b: usize) {
+        match self {
+            Snail::Num(n) => *n += b,
+            Snail::Pair(l, _) => l.add_left(b),
+        };
+    }
+
+    fn add_right(&mut self, b: usize) {
+        match self {
+            Snail::Num(n) => *n += b,
+            Snail::Pair(_, r) => r.add_right(b),
+        };
+    }
+
+    // How many pairs deep the innermost number is
+    #[cfg(feature = "serde")]
+    fn depth(&self) -> usize {
+        match self {
+            Snail::Num(_) => 0,
+            Snail::Pair(a, b) => 1 + a.depth().max(b.depth()),
+        }
+    }
+
+    fn magnitude(&self) -> usize {
+        match self {
+            Snail::Num(n) => *n,
+            Snail::Pair(a, b) => 3 * a.magnitude() + 2 * b.magnitude(),
+        }
+    }
+
+    fn split(&mut self, tracer: &mut impl Tracer) -> bool {
+        match self {
+            Snail::Num(n) if *n < 10 => false,
+            Snail::Num(n) => {
+                tracer.trace(|| Event::Split { value: *n });
+                *self = Snail::new(*n / 2, n.div_ceil(2));
+                true
+            }
+            Snail::Pair(a, b) => a.split(tracer) || b.split(tracer),
+        }
+    }
+
+    fn reduce(mut self, tracer: &mut impl Tracer) -> Self {
+        while self.explode(0, tracer).is_some() || self.split(tracer) {}
+
+        self
+    }
+
+    fn explode(&mut self, depth: usize, tracer: &mut impl Tracer) -> Option<(usize, usize)> {
+        match self {
+            Snail::Num(_) => None,
+            Snail::Pair(a, b) => match (a.as_mut(), b.as_mut()) {
+                (Snail::Num(x), Snail::Num(y)) if depth == 4 => {
+                    let x = *x;
+                    let y = *y;
+                    tracer.trace(|| Event::Explode { left: x, right: y });
+                    *self = Snail::Num(0);
+                    Some((x, y))
+                }
+                (Snail::Num(_), Snail::Num(_)) => None,
+                (l, r) => {
+                    if let Some((lhs_left, lhs_right)) = l.explode(depth + 1, tracer) {
+                        b.add_left(lhs_right);
+                        Some((lhs_left, 0))
+                    } else if let Some((rhs_left, rhs_right)) = r.explode(depth + 1, tracer) {
+                        a.add_right(rhs_left);
+                        Some((0, rhs_right))
+                    } else {
+                        None
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl FromStr for Snail {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_line(&Source::new(18, s), s)
+    }
+}
+
+fn parse_line(src: &Source, s: &str) -> Result<Snail, ParseError> {
+    // Only reduced numbers are added: no pair nested in four others and no number above 9. That
+    // bounds the work reducing a sum, and checking the nesting first keeps the parser from
+    // recursing arbitrarily deep
+    let mut depth = 0_usize;
+    for (i, c) in s.char_indices() {
+        let reduced = match c {
+            '[' => {
+                depth += 1;
+                depth <= 4
+            }
+            ']' => {
+                depth = depth.saturating_sub(1);
+                true
+            }
+            _ => !(c.is_ascii_digit() && s[i + 1..].starts_with(|c: char| c.is_ascii_digit())),
+        };
+
+        if !reduced {
+            return Err(src.error(
+                &s[i..i + c.len_utf8()],
+                "expected a reduced snailfish number",
+            ));
+        }
+    }
+
+    match all_consuming(parse).parse(s) {
+        Ok((_, snail)) => Ok(snail),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(src.error(err.input, "invalid snailfish number"))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(src.error(s, "incomplete snailfish number")),
+    }
+}
+
+fn parse(s: &str) -> IResult<&str, Snail> {
+    delimited(char('['), pair, char(']')).parse(s)
+}
+
+fn pair(s: &str) -> IResult<&str, Snail> {
+    map(
+        separated_pair(alt((number, parse)), char(','), alt((number, parse))),
+        |(left, right)| Snail::new(left, right),
+    )
+    .parse(s)
+}
+
+fn number(s: &str) -> IResult<&str, Snail> {
+    map_res(digit1, |n: &str| n.parse().map(Snail::Num)).parse(s)
+}
+
+fn add(left: Snail, right: Snail) -> Snail {
+    Snail::new(left, right)
+}
+
+#[aoc_generator(day18)]
+pub fn generator(input: &str) -> Result<Vec<Snail>, ParseError> {
+    let src = Source::new(18, input);
+    let snails = input
+        .lines()
+        .map(|x| parse_line(&src, x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if snails.is_empty() {
+        return Err(src.error(input, "expected at least one snailfish number"));
+    }
+
+    Ok(snails)
+}
+
+#[aoc(day18, part1)]
+pub fn part1(inputs: &[Snail]) -> usize {
+    part1_traced(inputs, &mut NoTrace)
+}
+
+/// [`part1`], tracing every explosion and split.
+pub fn part1_traced(inputs: &[Snail], tracer: &mut impl Tracer) -> usize {
+    inputs
+        .iter()
+        .cloned()
+        .reduce(|acc, snailfish| add(acc, snailfish).reduce(tracer))
+        // The generator never returns an empty list, but a deserialized one can be
+        .map_or(0, |sum| sum.magnitude())
+}
+
+#[aoc(day18, part2)]
+pub fn part2(inputs: &[Snail]) -> usize {
+    part2_traced(inputs, &mut NoTrace)
+}
+
+/// [`part2`], tracing every explosion and split of every pair tried.
+pub fn part2_traced(inputs: &[Snail], tracer: &mut impl Tracer) -> usize {
+    let mut max = 0;
+
+    for a in inputs.iter() {
+        for b in inputs.iter() {
+            if a == b {
+                continue;
+            }
+            max = max.max(add(a.clone(), b.clone()).reduce(tracer).magnitude())
+        }
+    }
+
+    max
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 18;
+
+    type Parsed = Vec<Snail>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    #[test]
+    pub fn test_input() {
+        for x in generator(SAMPLE).unwrap() {
+            println!("{:?}", x);
+        }
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 4140);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 3993);
+    }
+
+    #[test]
+    pub fn test_invalid() {
+        for input in ["", "[1,2", "[[[[[1,2],3],4],5],6]", "[10,1]", "[1,[2,03]]"] {
+            assert!(generator(input).is_err(), "{input:?}");
+        }
+
+        let err = generator("[1,2]\n[[[[[1,2],3],4],5],6]").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let snails = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&snails).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Snail>>(&json).unwrap(), snails);
+
+        let nested: Snail = "[[[[1,2],3],4],5]".parse().unwrap();
+        let json = serde_json::to_string(&Snail::new(nested, Snail::Num(6))).unwrap();
+        assert!(serde_json::from_str::<Snail>(&json).is_err());
+        assert!(serde_json::from_str::<Snail>(r#"{"Num":10}"#).is_err());
+
+        assert_eq!(part1(&[]), 0);
+    }
+
+    #[test]
+    pub fn test_trace() {
+        let mut events = Vec::new();
+        part1_traced(
+            &generator("[[[[4,3],4],4],[7,[[8,4],9]]]\n[1,1]").unwrap(),
+            &mut events,
+        );
+
+        assert_eq!(
+            events,
+            [
+                Event::Explode { left: 4, right: 3 },
+                Event::Explode { left: 8, right: 4 },
+                Event::Split { value: 15 },
+                Event::Split { value: 13 },
+                Event::Explode { left: 6, right: 7 },
+            ]
+        );
+    }
+}
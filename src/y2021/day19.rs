@@ -0,0 +1,512 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter,
+    ops::{Add, Sub},
+};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use arrayvec::ArrayVec;
+use itertools::Itertools;
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::{
+    error::{ParseError, Source},
+    par::prelude::*,
+    solution::Solution,
+    trace::{Event, NoTrace, Tracer},
+};
+
+type C = i32;
+
+const RANGE: C = 1000;
+
+#[derive(Eq, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCoord3"))]
+pub struct Coord3(C, C, C);
+
+// A beacon as serialized, checked like a parsed one so the sums and distances stay inside `C`
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCoord3(C, C, C);
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCoord3> for Coord3 {
+    type Error = String;
+
+    fn try_from(RawCoord3(x, y, z): RawCoord3) -> Result<Self, Self::Error> {
+        let c = Coord3(x, y, z);
+        if !c.in_range() {
+            return Err(format!("beacons must be within {RANGE} of the scanner"));
+        }
+
+        Ok(c)
+    }
+}
+
+impl Hash for Coord3 {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_i32(self.0 ^ self.1 ^ self.2);
+    }
+}
+
+impl PartialEq for Coord3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1 && self.2 == other.2
+    }
+}
+
+impl Display for Coord3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{},{}]", self.0, self.1, self.2)
+    }
+}
+
+impl Debug for Coord3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Add for Coord3 {
+    type Output = Coord3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Coord3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub for Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coord3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Coord3 {
+    // Scanners only see beacons up to 1000 units away, which also keeps the sums and distances
+    // between them well inside `C`
+    fn in_range(&self) -> bool {
+        [self.0, self.1, self.2]
+            .iter()
+            .all(|x| (-RANGE..=RANGE).contains(x))
+    }
+
+    fn distance(&self, other: &Self) -> C {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs() + (self.2 - other.2).abs()
+    }
+
+    fn rot_xy(&self) -> Self {
+        Coord3(self.1, -self.0, self.2)
+    }
+
+    fn rot_xz(&mut self) -> Self {
+        Coord3(self.2, self.1, -self.0)
+    }
+
+    fn transforms(&self) -> [Coord3; 24] {
+        let mut r = [Coord3(0, 0, 0); 24];
+
+        r[0] = *self;
+        r[1] = r[0].rot_xy();
+        r[2] = r[1].rot_xy();
+        r[3] = r[2].rot_xy();
+
+        for x in 0..5 {
+            r[x * 4 + 4] = r[x].rot_xz();
+            r[x * 4 + 5] = r[x * 4 + 4].rot_xy();
+            r[x * 4 + 6] = r[x * 4 + 5].rot_xy();
+            r[x * 4 + 7] = r[x * 4 + 6].rot_xy();
+        }
+
+        r
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scanner(Vec<Coord3>);
+
+impl Scanner {
+    fn transforms(&self) -> ArrayVec<Scanner, 24> {
+        let mut res: ArrayVec<_, 24> = iter::repeat_n(Scanner::default(), 24).collect();
+
+        for beacon in self.0.iter().map(|b| b.transforms()) {
+            for (i, b) in beacon.iter().enumerate() {
+                res[i].0.push(*b);
+            }
+        }
+
+        res
+    }
+}
+
+fn parse_vector(src: &Source, s: &str) -> Result<Coord3, ParseError> {
+    let mut it = s.split(',').map(|x| src.parse::<C>(x));
+    let mut next = || src.expect(it.next(), s, "expected `x,y,z`").and_then(|c| c);
+
+    let c = Coord3(next()?, next()?, next()?);
+    if it.next().is_some() {
+        return Err(src.error(s, "expected `x,y,z`"));
+    }
+    if !c.in_range() {
+        return Err(src.error(s, format!("beacons must be within {RANGE} of the scanner")));
+    }
+
+    Ok(c)
+}
+
+fn parse_chunk(src: &Source, s: &str) -> Result<Scanner, ParseError> {
+    let mut lines = s.lines();
+    let header = lines.next().unwrap_or(s);
+    if !header.starts_with("--- scanner ") {
+        return Err(src.error(header, "expected `--- scanner N ---`"));
+    }
+
+    lines
+        .map(|l| parse_vector(src, l))
+        .collect::<Result<_, _>>()
+        .map(Scanner)
+}
+
+#[aoc_generator(day19)]
+pub fn generator(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    let src = Source::new(19, input);
+    let chunk = input.split("\n\n");
+    chunk.map(|s| parse_chunk(&src, s)).collect()
+}
+
+// Takes the first scanner, and its first rotation, that overlaps `total`. `find_map_first` keeps that
+// choice the same with or without the `parallel` feature, even if several rotations would fit.
+fn solve(
+    scanners: &[Scanner],
+    total: &HashSet<Coord3>,
+) -> Option<(Coord3, HashSet<Coord3>, usize)> {
+    (0..scanners.len()).find_map(|i| {
+        par_iter(&scanners[i].transforms())
+            .find_map_first(|r| merge(total, r))
+            .map(|(d, s)| (d, s, i))
+    })
+}
+
+fn merge(t: &HashSet<Coord3>, r: &Scanner) -> Option<(Coord3, HashSet<Coord3>)> {
+    let mut nt = t.clone();
+
+    for d in nt
+        .iter()
+        .cartesian_product(r.0.iter())
+        .map(|(&t, &c)| t - c)
+    {
+        let mut count = 0;
+        let mut remain = r.0.len();
+
+        for c in r.0.iter() {
+            let v: Coord3 = *c + d;
+            remain -= 1;
+
+            if t.contains(&v) {
+                count += 1;
+
+                if count == 12 {
+                    nt.extend(r.0.iter().map(|&c| c + d));
+
+                    return Some((d, nt));
+                }
+            } else if remain + count < 12 {
+                // We can't ever reach 12, so stop
+
+                break;
+            }
+        }
+    }
+
+    None
+}
+
+// Merges every scanner into the first, returning the beacons and, in the order they were merged,
+// each scanner's index in `inputs` and its position relative to the first
+fn assemble(
+    inputs: &[Scanner],
+    tracer: &mut impl Tracer,
+) -> (HashSet<Coord3>, Vec<(usize, Coord3)>) {
+    let mut inputs: Vec<_> = inputs.iter().rev().cloned().collect();
+    let mut ids: Vec<_> = (0..inputs.len()).rev().collect();
+    let mut order = Vec::new();
+
+    // The generator always returns a scanner, a deserialized list might not have one
+    ids.pop();
+    let mut t = inputs.pop().unwrap_or_default().0.into_iter().collect();
+    while let Some((dist, nt, i)) = solve(&inputs, &t) {
+        t = nt;
+        inputs.swap_remove(i);
+        let scanner = ids.swap_remove(i);
+        tracer.trace(|| Event::Merge {
+            scanner,
+            position: (dist.0, dist.1, dist.2),
+            beacons: t.len(),
+        });
+        order.push((scanner, dist));
+    }
+
+    (t, order)
+}
+
+#[aoc(day19, part1)]
+pub fn part1(inputs: &[Scanner]) -> usize {
+    part1_traced(inputs, &mut NoTrace)
+}
+
+/// [`part1`], tracing every scanner as it is merged.
+pub fn part1_traced(inputs: &[Scanner], tracer: &mut impl Tracer) -> usize {
+    assemble(inputs, tracer).0.len()
+}
+
+#[aoc(day19, part2)]
+pub fn part2(inputs: &[Scanner]) -> C {
+    part2_traced(inputs, &mut NoTrace)
+}
+
+/// [`part2`], tracing every scanner as it is merged.
+pub fn part2_traced(inputs: &[Scanner], tracer: &mut impl Tracer) -> C {
+    // Fewer than two scanners merged have no distance between them
+    assemble(inputs, tracer)
+        .1
+        .iter()
+        .tuple_combinations()
+        .map(|((_, a), (_, b))| a.distance(b))
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 19;
+
+    type Parsed = Vec<Scanner>;
+    type Answer1 = usize;
+    type Answer2 = C;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> C {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    mod input {
+        pub const SAMPLE: &str = r"--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14";
+    }
+
+    #[test]
+    pub fn test_input() {
+        // assert_eq!(generator(SAMPLE), Day19());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(input::SAMPLE).unwrap()), 79);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(input::SAMPLE).unwrap()), 3621);
+        assert_eq!(part2(&generator("--- scanner 0 ---\n1,2,3").unwrap()), 0);
+
+        // Only the scanners merged into the first are measured, not the first itself
+        let beacons: Vec<_> = (1..=12).map(|i| (i * 7, i * i, i * 3 - 40)).collect();
+        let scanner = |n: i32, dx: i32| {
+            let lines = beacons
+                .iter()
+                .map(|(x, y, z)| format!("{},{y},{z}", x - dx));
+            format!("--- scanner {n} ---\n{}", lines.format("\n"))
+        };
+        let input = format!("{}\n\n{}", scanner(0, 0), scanner(1, 100));
+        let scanners = generator(&input).unwrap();
+        assert_eq!((part1(&scanners), part2(&scanners)), (12, 0));
+    }
+
+    #[test]
+    pub fn test_range() {
+        assert!(generator("--- scanner 0 ---\n1000,-1000,0").is_ok());
+        assert!(generator("--- scanner 0 ---\n1001,0,0").is_err());
+        assert!(generator("--- scanner 0 ---\n0,0,-2147483648").is_err());
+    }
+
+    // The merge order must not depend on the `parallel` feature or on thread scheduling
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let scanners = generator("--- scanner 0 ---\n1,2,3\n-4,5,-6").unwrap();
+        let json = serde_json::to_string(&scanners).unwrap();
+        assert_eq!(json, "[[[1,2,3],[-4,5,-6]]]");
+        assert!(serde_json::from_str::<Vec<Scanner>>(&json).unwrap() == scanners);
+
+        assert!(serde_json::from_str::<Vec<Scanner>>("[[[1,2,1001]]]").is_err());
+        assert_eq!((part1(&[]), part2(&[])), (0, 0));
+    }
+
+    #[test]
+    pub fn test_merge_order() {
+        let (_, order) = assemble(&generator(input::SAMPLE).unwrap(), &mut NoTrace);
+
+        assert_eq!(
+            order,
+            [
+                (1, Coord3(68, -1246, -43)),
+                (4, Coord3(-20, -1133, 1061)),
+                (2, Coord3(1105, -1205, 1229)),
+                (3, Coord3(-92, -2380, -20)),
+            ]
+        );
+    }
+}
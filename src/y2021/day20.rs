@@ -0,0 +1,252 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    grid::Grid,
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDay20"))]
+pub struct Day20 {
+    enhancement: Vec<bool>,
+    image: Image,
+}
+
+// The puzzle as serialized, its algorithm checked like a parsed one
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawDay20 {
+    enhancement: Vec<bool>,
+    image: Image,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDay20> for Day20 {
+    type Error = &'static str;
+
+    fn try_from(RawDay20 { enhancement, image }: RawDay20) -> Result<Self, Self::Error> {
+        check_algorithm(&enhancement)?;
+
+        Ok(Day20 { enhancement, image })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Image {
+    grid: Grid<bool>,
+    // Every pixel outside the grid, which flips each step when the algorithm maps 0 to lit
+    background: bool,
+}
+
+impl Image {
+    fn get(&self, row: isize, col: isize) -> bool {
+        usize::try_from(row)
+            .ok()
+            .zip(usize::try_from(col).ok())
+            .and_then(|p| self.grid.get(p))
+            .copied()
+            .unwrap_or(self.background)
+    }
+
+    fn extract_zone(&self, row: isize, col: isize) -> usize {
+        [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 0),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ]
+        .iter()
+        .map(|(dy, dx)| usize::from(self.get(row + dy, col + dx)))
+        .fold(0, |acc, n| acc * 2 + n)
+    }
+}
+
+#[aoc_generator(day20)]
+pub fn generator(input: &str) -> Result<Day20, ParseError> {
+    let src = Source::new(20, input);
+    let (a, b) = src.expect(
+        input.split_once("\n\n"),
+        input,
+        "expected algorithm and image separated by a blank line",
+    )?;
+    let enhancement = a
+        .char_indices()
+        .map(|(i, c)| {
+            src.expect(
+                parse_pixel(c),
+                &a[i..i + c.len_utf8()],
+                "expected `#` or `.`",
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Err(reason) = check_algorithm(&enhancement) {
+        // Every pixel is one byte, so the last one is at 511
+        let at = if enhancement.len() == 512 {
+            &a[511..]
+        } else {
+            a
+        };
+        return Err(src.error(at, reason));
+    }
+
+    Ok(Day20 {
+        enhancement,
+        image: Image {
+            grid: Grid::parse(&src, b, parse_pixel, "expected `#` or `.`")?,
+            background: false,
+        },
+    })
+}
+
+// Every 3x3 zone looks up one of 512 pixels
+fn check_algorithm(enhancement: &[bool]) -> Result<(), &'static str> {
+    if enhancement.len() != 512 {
+        return Err("expected 512 pixels in the algorithm");
+    }
+    // Otherwise the background lights up for good and so do infinitely many pixels
+    if enhancement[0] && enhancement[511] {
+        return Err(
+            "an algorithm that lights dark and lit backgrounds lights infinitely many pixels",
+        );
+    }
+
+    Ok(())
+}
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn tick(lookup: &[bool], image: &Image) -> Image {
+    let (width, height) = (image.grid.width() + 2, image.grid.height() + 2);
+    let mut grid = Grid::new(width, height, false);
+
+    // The new image grows by one pixel on every side
+    for (r, c) in grid.positions() {
+        let pos = image.extract_zone(r as isize - 1, c as isize - 1);
+        grid[(r, c)] = lookup[pos];
+    }
+
+    Image {
+        grid,
+        background: lookup[if image.background { 511 } else { 0 }],
+    }
+}
+
+fn solve<const N: usize>(inputs: &Day20) -> usize {
+    let mut image = inputs.image.clone();
+
+    for _ in 0..N {
+        image = tick(&inputs.enhancement, &image);
+    }
+    image.grid.iter().filter(|&&lit| lit).count()
+}
+
+#[aoc(day20, part1)]
+pub fn part1(inputs: &Day20) -> usize {
+    solve::<2>(inputs)
+}
+
+#[aoc(day20, part2)]
+pub fn part2(inputs: &Day20) -> usize {
+    solve::<50>(inputs)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 20;
+
+    type Parsed = Day20;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test_extract() {
+        let d = generator(SAMPLE).unwrap();
+
+        assert_eq!(d.image.extract_zone(2, 2), 34)
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 35);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 3351);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        let puzzle = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&puzzle).unwrap();
+        assert_eq!(serde_json::from_str::<Day20>(&json).unwrap(), puzzle);
+
+        let mut short = generator(SAMPLE).unwrap();
+        short.enhancement.pop();
+        let mut lit = generator(SAMPLE).unwrap();
+        (lit.enhancement[0], lit.enhancement[511]) = (true, true);
+        for puzzle in [short, lit] {
+            let json = serde_json::to_string(&puzzle).unwrap();
+            assert!(serde_json::from_str::<Day20>(&json).is_err());
+        }
+    }
+
+    #[test]
+    pub fn test_background() {
+        // A flashing background is fine, it's dark again after every second step
+        let flashing = format!("#{}\n\n#", ".".repeat(511));
+        assert_eq!(part1(&generator(&flashing).unwrap()), 1);
+
+        let lit = format!("#{}#\n\n#", ".".repeat(510));
+        assert!(generator(&lit).is_err());
+    }
+}
@@ -1,24 +1,57 @@
 use std::str::FromStr;
 
-use super::common::utils::build_array;
+use crate::utils::try_build_array;
 use ahash::AHashMap as HashMap;
 use aoc_runner_derive::{aoc, aoc_generator};
 //use rustc_hash::FxHashMap as HashMap;
 
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawPlayer"))]
 pub struct Player {
     pos: u8,
     score: u16,
 }
 
+// A player as serialized, on the track like a parsed one and yet to win, so the game can go on
+// without overflowing
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPlayer {
+    pos: u8,
+    score: u16,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPlayer> for Player {
+    type Error = &'static str;
+
+    fn try_from(RawPlayer { pos, score }: RawPlayer) -> Result<Self, Self::Error> {
+        if !on_track(pos) {
+            return Err("position must be between 1 and 10");
+        }
+        if score >= 1000 {
+            return Err("score must be below 1000");
+        }
+
+        Ok(Player::new_with_score(pos, score))
+    }
+}
+
+fn on_track(pos: u8) -> bool {
+    (1..=10).contains(&pos)
+}
+
 impl FromStr for Player {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, x) = s.split_once(':').ok_or("split_once(':') failed")?;
-        let pos = x.trim_start().parse().map_err(|_| "u8::parse failed")?;
-
-        Ok(Self::new(pos))
+        Self::parse(&Source::new(21, s), s)
     }
 }
 
@@ -49,6 +82,21 @@ impl Dice {
 }
 
 impl Player {
+    fn parse(src: &Source, s: &str) -> Result<Self, ParseError> {
+        let (_, x) = src.expect(
+            s.split_once(':'),
+            s,
+            "expected `Player N starting position: P`",
+        )?;
+        let x = x.trim_start();
+        let pos = src.parse(x)?;
+        if !on_track(pos) {
+            return Err(src.error(x, "position must be between 1 and 10"));
+        }
+
+        Ok(Self::new(pos))
+    }
+
     fn new(pos: u8) -> Self {
         Self { pos, score: 0 }
     }
@@ -72,8 +120,14 @@ impl Player {
 }
 
 #[aoc_generator(day21)]
-pub fn generator(input: &str) -> [Player; 2] {
-    build_array(input.lines().map(|l| l.parse().unwrap()))
+pub fn generator(input: &str) -> Result<[Player; 2], ParseError> {
+    let src = Source::new(21, input);
+    let players = input
+        .lines()
+        .map(|l| Player::parse(&src, l))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    src.expect(try_build_array(players), input, "expected 2 players")
 }
 
 #[aoc(day21, part1)]
@@ -132,6 +186,29 @@ pub fn part2(inputs: &[Player]) -> usize {
     solve2(&mut HashMap::default(), inputs[0], inputs[1]).0
 }
 
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 21;
+
+    type Parsed = [Player; 2];
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> usize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> usize {
+        part2(parsed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,7 +218,7 @@ Player 2 starting position: 8";
 
     #[test]
     pub fn test_input() {
-        println!("{:?}", generator(SAMPLE));
+        println!("{:?}", generator(SAMPLE).unwrap());
 
         // assert_eq!(generator(SAMPLE), Object());
         use itertools::Itertools;
@@ -155,29 +232,29 @@ Player 2 starting position: 8";
         println!("{:?}", a);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    pub fn test1() {
-        assert_eq!(part1(&generator(SAMPLE)), 739785);
+    pub fn test_serde() {
+        let players = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&players).unwrap();
+        assert_eq!(serde_json::from_str::<[Player; 2]>(&json).unwrap(), players);
+
+        for json in [
+            r#"{"pos":0,"score":0}"#,
+            r#"{"pos":11,"score":0}"#,
+            r#"{"pos":1,"score":1000}"#,
+        ] {
+            assert!(serde_json::from_str::<Player>(json).is_err(), "{json}");
+        }
     }
 
     #[test]
-    pub fn test2() {
-        assert_eq!(part2(&generator(SAMPLE)), 444356092776315);
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 739785);
     }
 
-    mod regression {
-        use super::*;
-
-        const INPUT: &str = include_str!("../input/2021/day21.txt");
-        const ANSWERS: (usize, usize) = (921585, 911090395997650);
-
-        #[test]
-        pub fn test() {
-            let input = INPUT.trim_end_matches('\n');
-            let output = generator(input);
-
-            assert_eq!(part1(&output), ANSWERS.0);
-            assert_eq!(part2(&output), ANSWERS.1);
-        }
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE).unwrap()), 444356092776315);
     }
 }
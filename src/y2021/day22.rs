@@ -1,10 +1,16 @@
-use std::{convert::Infallible, str::FromStr};
+use std::str::FromStr;
 
-use super::common::utils::parse_range;
+use crate::utils::parse_range;
+use crate::{
+    error::{ParseError, Source},
+    par::prelude::*,
+    solution::Solution,
+};
 use aoc_runner_derive::{aoc, aoc_generator};
-use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCuboid"))]
 pub struct Cuboid {
     kind: bool,
     x: (isize, isize),
@@ -12,24 +18,85 @@ pub struct Cuboid {
     z: (isize, isize),
 }
 
+// A cuboid as serialized, checked like a parsed one so its volume fits
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCuboid {
+    kind: bool,
+    x: (isize, isize),
+    y: (isize, isize),
+    z: (isize, isize),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCuboid> for Cuboid {
+    type Error = String;
+
+    fn try_from(RawCuboid { kind, x, y, z }: RawCuboid) -> Result<Self, Self::Error> {
+        if ![x, y, z].into_iter().all(in_range) {
+            return Err(format!(
+                "expected `min <= max` between -{RANGE} and {RANGE}"
+            ));
+        }
+
+        Ok(Cuboid { kind, x, y, z })
+    }
+}
+
+// Small enough that the volume of a cuboid spanning the whole range fits in an `isize`, and with it
+// every count, which never exceeds the volume of the cuboids it covers
+const RANGE: isize = 1_000_000;
+
+fn in_range((min, max): (isize, isize)) -> bool {
+    -RANGE <= min && min <= max && max <= RANGE
+}
+
 impl FromStr for Cuboid {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&Source::new(22, s), s)
+    }
+}
+
+impl Cuboid {
+    fn parse(src: &Source, s: &str) -> Result<Self, ParseError> {
         // on x=-3..43,y=-28..22,z=-6..38
         // 00 1 222222 3 4444444 5 666666
         let mut itr = s.split(&[' ', '=', ','][..]);
-        let kind = itr.next().unwrap() == "on";
+        let kind = match itr.next() {
+            Some("on") => true,
+            Some("off") => false,
+            _ => return Err(src.error(s, "expected `on` or `off`")),
+        };
 
-        let x = parse_range(itr.nth(1).unwrap()).unwrap();
-        let y = parse_range(itr.nth(1).unwrap()).unwrap();
-        let z = parse_range(itr.nth(1).unwrap()).unwrap();
+        let mut range = |axis| {
+            let format = "expected `on|off x=<min>..<max>,y=<min>..<max>,z=<min>..<max>`";
+            if itr.next() != Some(axis) {
+                return Err(src.error(s, format));
+            }
+
+            let r = src.expect(itr.next(), s, format)?;
+            match parse_range(r) {
+                Some(r) if in_range(r) => Ok(r),
+                Some((min, max)) if min <= max => Err(src.error(
+                    r,
+                    format!("coordinates must be between -{RANGE} and {RANGE}"),
+                )),
+                _ => Err(src.error(r, "expected `<min>..<max>`")),
+            }
+        };
+
+        let x = range("x")?;
+        let y = range("y")?;
+        let z = range("z")?;
+        if itr.next().is_some() {
+            return Err(src.error(s, "unexpected text after the z range"));
+        }
 
         Ok(Self { kind, x, y, z })
     }
-}
 
-impl Cuboid {
     fn count(&self, others: &[Self]) -> isize {
         let conflicts = others
             .iter()
@@ -72,14 +139,14 @@ fn range_overlap(range: (isize, isize), other: (isize, isize)) -> Option<(isize,
 }
 
 #[aoc_generator(day22)]
-pub fn generator(input: &str) -> Vec<Cuboid> {
-    input.lines().map(|l| l.parse().unwrap()).collect()
+pub fn generator(input: &str) -> Result<Vec<Cuboid>, ParseError> {
+    let src = Source::new(22, input);
+    input.lines().map(|l| Cuboid::parse(&src, l)).collect()
 }
 
 #[aoc(day22, part1)]
 pub fn part1(inputs: &[Cuboid]) -> isize {
-    inputs
-        .par_iter()
+    par_iter(inputs)
         .enumerate()
         .filter(|(_, cuboid)| cuboid.kind)
         .filter_map(|(i, cuboid)| {
@@ -98,14 +165,36 @@ pub fn part1(inputs: &[Cuboid]) -> isize {
 
 #[aoc(day22, part2)]
 pub fn part2(inputs: &[Cuboid]) -> isize {
-    inputs
-        .par_iter()
+    par_iter(inputs)
         .enumerate()
         .filter(|(_, cuboid)| cuboid.kind)
         .map(|(i, cuboid)| cuboid.count(&inputs[i + 1..]))
         .sum()
 }
 
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 22;
+
+    type Parsed = Vec<Cuboid>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> isize {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> isize {
+        part2(parsed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,34 +285,54 @@ off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
 
     #[test]
     pub fn test_input() {
-        println!("{:?}", generator(SAMPLE));
+        println!("{:?}", generator(SAMPLE).unwrap());
 
         // assert_eq!(generator(SAMPLE), Object());
     }
 
     #[test]
-    pub fn test1() {
-        assert_eq!(part1(&generator(SAMPLE)), 590784);
+    pub fn test_invalid() {
+        let err = generator("on x=-20..26,y=-36..17,z=-47..7\non x=1..2,y=3,z=4..5").unwrap_err();
+
+        assert_eq!((err.day, err.line, err.column), (22, 2, 13));
+        assert_eq!(err.text, "3");
+        assert_eq!(
+            err.to_string(),
+            "day 22, line 2, column 13: expected `<min>..<max>` (`3`)"
+        );
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    pub fn test2() {
-        assert_eq!(part2(&generator(SAMPLE2)), 2_758_514_936_282_235);
+    pub fn test_serde() {
+        let cuboids = generator(SAMPLE).unwrap();
+        let json = serde_json::to_string(&cuboids).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Cuboid>>(&json).unwrap(), cuboids);
+
+        for json in [
+            r#"{"kind":true,"x":[2,1],"y":[0,0],"z":[0,0]}"#,
+            r#"{"kind":true,"x":[0,0],"y":[0,1000001],"z":[0,0]}"#,
+        ] {
+            assert!(serde_json::from_str::<Cuboid>(json).is_err(), "{json}");
+        }
     }
 
-    mod regression {
-        use super::*;
+    #[test]
+    pub fn test_range() {
+        let whole = "on x=-1000000..1000000,y=-1000000..1000000,z=-1000000..1000000";
+        assert_eq!(part2(&generator(whole).unwrap()), 2_000_001_isize.pow(3));
 
-        const INPUT: &str = include_str!("../input/2021/day22.txt");
-        const ANSWERS: (isize, isize) = (648023, 1_285_677_377_848_549);
+        let err = generator("on x=0..1000001,y=0..1,z=0..1").unwrap_err();
+        assert_eq!(err.text, "0..1000001");
+    }
 
-        #[test]
-        pub fn test() {
-            let input = INPUT.trim_end_matches('\n');
-            let output = generator(input);
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), 590784);
+    }
 
-            assert_eq!(part1(&output), ANSWERS.0);
-            assert_eq!(part2(&output), ANSWERS.1);
-        }
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator(SAMPLE2).unwrap()), 2_758_514_936_282_235);
     }
 }
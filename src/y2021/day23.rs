@@ -6,6 +6,12 @@ use std::{
 use aoc_runner_derive::{aoc, aoc_generator};
 use pathfinding::prelude::dijkstra;
 
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+    trace::{Event, NoTrace, Tracer},
+};
+
 // #############
 // #01.2.3.4.56#
 // ###D#A#C#D###
@@ -14,21 +20,17 @@ use pathfinding::prelude::dijkstra;
 //   #C#A#B#B#
 //   #########
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Amphipod {
     Amber,
     Bronze,
     Copper,
     Desert,
+    #[default]
     Empty,
 }
 
-impl Default for Amphipod {
-    fn default() -> Self {
-        Self::Empty
-    }
-}
-
 impl Display for Amphipod {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -52,18 +54,19 @@ impl Amphipod {
         }
     }
 
-    fn parse(c: u8) -> Self {
+    fn parse(c: u8) -> Option<Self> {
         match c {
-            b'A' => Amphipod::Amber,
-            b'B' => Amphipod::Bronze,
-            b'C' => Amphipod::Copper,
-            b'D' => Amphipod::Desert,
-            _ => unreachable!(),
+            b'A' => Some(Amphipod::Amber),
+            b'B' => Some(Amphipod::Bronze),
+            b'C' => Some(Amphipod::Copper),
+            b'D' => Some(Amphipod::Desert),
+            _ => None,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hallway([Amphipod; 7]);
 impl Display for Hallway {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -129,7 +132,9 @@ impl Hallway {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Room<const SIZE: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_array"))]
     slots: [Amphipod; SIZE],
     kind: Amphipod,
 }
@@ -185,11 +190,58 @@ impl<const SIZE: usize> Room<SIZE> {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawMap<SIZE>"))]
 pub struct Map<const SIZE: usize> {
     hallway: Hallway,
     rooms: [Room<SIZE>; 4],
 }
 
+// A burrow as serialized, checked so every room can end up full of its own kind like a parsed one
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawMap<const SIZE: usize> {
+    hallway: Hallway,
+    rooms: [Room<SIZE>; 4],
+}
+
+#[cfg(feature = "serde")]
+impl<const SIZE: usize> TryFrom<RawMap<SIZE>> for Map<SIZE> {
+    type Error = String;
+
+    fn try_from(RawMap { hallway, rooms }: RawMap<SIZE>) -> Result<Self, Self::Error> {
+        let map = Map { hallway, rooms };
+
+        for (room, kind) in map.rooms.iter().zip(KINDS) {
+            if room.kind != kind {
+                return Err(format!("expected the rooms to be for {KINDS:?} in order"));
+            }
+            // Amphipods stand at the bottom of their room, the solver only ever fills it from there
+            if room
+                .slots
+                .iter()
+                .skip_while(|&&a| a == Amphipod::Empty)
+                .any(|&a| a == Amphipod::Empty)
+            {
+                return Err("expected empty slots only above the amphipods in a room".to_string());
+            }
+        }
+
+        let amphipods = map
+            .rooms
+            .iter()
+            .flat_map(|room| room.slots)
+            .chain(map.hallway.0);
+        for kind in KINDS {
+            if amphipods.clone().filter(|&a| a == kind).count() != SIZE {
+                return Err(format!("expected {SIZE} of each amphipod"));
+            }
+        }
+
+        Ok(map)
+    }
+}
+
 impl<const SIZE: usize> Display for Map<SIZE> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "#############")?;
@@ -219,6 +271,13 @@ impl<const SIZE: usize> Debug for Map<SIZE> {
     }
 }
 
+const KINDS: [Amphipod; 4] = [
+    Amphipod::Amber,
+    Amphipod::Bronze,
+    Amphipod::Copper,
+    Amphipod::Desert,
+];
+
 impl<const SIZE: usize> Map<SIZE> {
     fn new() -> Self {
         Self {
@@ -314,53 +373,126 @@ fn generate_room_to_slot<const SIZE: usize>(
     })
 }
 
+// Reads the 8 Amphipods from the diagram, in row major order
+fn parse_amphipods(input: &str) -> Result<Vec<Amphipod>, ParseError> {
+    let src = Source::new(23, input);
+    let amphipods = input
+        .bytes()
+        .enumerate()
+        .filter(|(_, c)| c.is_ascii_alphabetic())
+        .map(|(i, c)| src.expect(Amphipod::parse(c), &input[i..=i], "expected A, B, C or D"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if amphipods.len() != 8 {
+        return Err(src.error(input, "expected 8 amphipods"));
+    }
+    // Each room has to end up full of one kind
+    for kind in KINDS {
+        if amphipods.iter().filter(|&&a| a == kind).count() != 2 {
+            return Err(src.error(input, "expected 2 of each amphipod"));
+        }
+    }
+
+    Ok(amphipods)
+}
+
 #[aoc_generator(day23, part1)]
-pub fn generator1(input: &str) -> Map<2> {
+pub fn generator1(input: &str) -> Result<Map<2>, ParseError> {
     let mut map = Map::new();
 
-    for (i, c) in input
-        .bytes()
-        .filter(|&c| c.is_ascii_alphabetic())
-        .enumerate()
-    {
-        map.rooms[i % 4].slots[i / 4] = Amphipod::parse(c);
+    for (i, amphipod) in parse_amphipods(input)?.into_iter().enumerate() {
+        map.rooms[i % 4].slots[i / 4] = amphipod;
     }
 
-    map
+    Ok(map)
 }
 
 #[aoc_generator(day23, part2)]
-pub fn generator2(input: &str) -> Map<4> {
+pub fn generator2(input: &str) -> Result<Map<4>, ParseError> {
     let mut map = Map::new();
 
-    let input = input.bytes().filter(|&c| c.is_ascii_alphabetic());
-    for (i, c) in input
-        .clone()
-        .take(4)
-        .chain("DCBADBAC".bytes())
-        .chain(input.skip(4))
+    let input = parse_amphipods(input)?;
+    let extra = "DCBADBAC".bytes().filter_map(Amphipod::parse);
+    for (i, amphipod) in input[..4]
+        .iter()
+        .copied()
+        .chain(extra)
+        .chain(input[4..].iter().copied())
         .enumerate()
     {
-        map.rooms[i % 4].slots[i / 4] = Amphipod::parse(c);
+        map.rooms[i % 4].slots[i / 4] = amphipod;
     }
 
-    map
+    Ok(map)
 }
 
-fn solve<const SIZE: usize>(start: &Map<SIZE>) -> usize {
-    dijkstra(start, |m| m.generate_move(), |m| m.is_done())
-        .unwrap()
-        .1
+// `None` if the amphipods block each other in for good, which can happen in the deeper burrow
+fn solve<const SIZE: usize>(start: &Map<SIZE>, tracer: &mut impl Tracer) -> Option<usize> {
+    let (path, cost) = dijkstra(start, |m| m.generate_move(), |m| m.is_done())?;
+
+    // Dijkstra only keeps the total, so find each move again to get its cost
+    let mut total = 0;
+    for step in path.windows(2) {
+        tracer.trace(|| {
+            let energy = step[0]
+                .generate_move()
+                .into_iter()
+                .find_map(|(map, energy)| (map == step[1]).then_some(energy))
+                .unwrap_or_default();
+            total += energy;
+
+            Event::Move {
+                energy,
+                total,
+                map: step[1].to_string(),
+            }
+        });
+    }
+
+    Some(cost)
 }
 
 #[aoc(day23, part1)]
-pub fn part1(inputs: &Map<2>) -> usize {
-    solve(inputs)
+pub fn part1(inputs: &Map<2>) -> Option<usize> {
+    part1_traced(inputs, &mut NoTrace)
+}
+
+/// [`part1`], tracing every move of the cheapest solution.
+pub fn part1_traced(inputs: &Map<2>, tracer: &mut impl Tracer) -> Option<usize> {
+    solve(inputs, tracer)
 }
 
 #[aoc(day23, part2)]
-pub fn part2(inputs: &Map<4>) -> usize {
-    solve(inputs)
+pub fn part2(inputs: &Map<4>) -> Option<usize> {
+    part2_traced(inputs, &mut NoTrace)
+}
+
+/// [`part2`], tracing every move of the cheapest solution.
+pub fn part2_traced(inputs: &Map<4>, tracer: &mut impl Tracer) -> Option<usize> {
+    solve(inputs, tracer)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 23;
+
+    type Parsed = (Map<2>, Map<4>);
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok((generator1(input)?, generator2(input)?))
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Option<usize> {
+        part1(&parsed.0)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<usize> {
+        part2(&parsed.1)
+    }
 }
 
 #[cfg(test)]
@@ -375,36 +507,64 @@ mod tests {
 
     #[test]
     pub fn test_input() {
-        println!("{}", generator1(SAMPLE));
+        println!("{}", generator1(SAMPLE).unwrap());
 
         // assert_eq!(generator(SAMPLE), Day23());
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    pub fn test1() {
-        assert_eq!(part1(&generator1(SAMPLE)), 12521);
+    pub fn test_serde() {
+        let map = generator1(SAMPLE).unwrap();
+        let json = serde_json::to_string(&map).unwrap();
+
+        assert_eq!(serde_json::from_str::<Map<2>>(&json).unwrap(), map);
+        assert!(serde_json::from_str::<Map<4>>(&json).is_err());
+
+        let mut swapped = map;
+        swapped.rooms.swap(0, 1);
+        let mut floating = map;
+        let [top, bottom] = floating.rooms[0].slots;
+        floating.rooms[0].slots = [bottom, Amphipod::Empty];
+        floating.hallway.0[0] = top;
+        let mut extra = map;
+        extra.hallway.0[0] = Amphipod::Amber;
+        for map in [swapped, floating, extra] {
+            let json = serde_json::to_string(&map).unwrap();
+            assert!(serde_json::from_str::<Map<2>>(&json).is_err(), "{map}");
+        }
     }
 
     #[test]
-    pub fn test2() {
-        assert_eq!(part2(&generator2(SAMPLE)), 44169);
+    pub fn test1() {
+        assert_eq!(part1(&generator1(SAMPLE).unwrap()), Some(12521));
     }
 
-    mod regression {
-        use super::*;
-
-        const INPUT: &str = include_str!("../input/2021/day23.txt");
-        const ANSWERS: (usize, usize) = (14346, 48984);
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&generator2(SAMPLE).unwrap()), Some(44169));
 
-        #[test]
-        pub fn test() {
-            let input = INPUT.trim_end_matches('\n');
+        // Solvable with two rows, but the extra amphipods leave no way through
+        let stuck = "#############\n#...........#\n###A#A#D#B###\n  #C#C#D#B#\n  #########";
+        assert_eq!(part1(&generator1(stuck).unwrap()), Some(11493));
+        assert_eq!(part2(&generator2(stuck).unwrap()), None);
+    }
 
-            let output1 = generator1(input);
-            assert_eq!(part1(&output1), ANSWERS.0);
+    #[test]
+    pub fn test_invalid() {
+        let err = generator1(&SAMPLE.replace('D', "A")).unwrap_err();
+        assert_eq!(err.reason, "expected 2 of each amphipod");
+    }
 
-            let output2 = generator2(input);
-            assert_eq!(part2(&output2), ANSWERS.1);
-        }
+    #[test]
+    pub fn test_trace() {
+        let mut events = Vec::new();
+        part1_traced(&generator1(SAMPLE).unwrap(), &mut events);
+
+        let Some(Event::Move { total, map, .. }) = events.last() else {
+            panic!("expected moves, got {events:?}");
+        };
+        assert_eq!(*total, 12521);
+        assert!(map.contains("#A#B#C#D#"), "{map}");
     }
 }
@@ -0,0 +1,170 @@
+use std::cmp::{max, min};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    solution::Solution,
+};
+
+fn parse_instruction(src: &Source, s: Option<&str>, chunk: &str) -> Result<isize, ParseError> {
+    let s = src.expect(s, chunk, "expected 18 instructions per digit")?;
+    let (_, n) = src.expect(s.rsplit_once(' '), s, "expected `<op> <reg> <value>`")?;
+
+    src.parse(n)
+}
+
+#[aoc_generator(day24)]
+pub fn generator(input: &str) -> Result<Vec<[isize; 3]>, ParseError> {
+    let src = Source::new(24, input);
+
+    let digits = input
+        .split("\ninp")
+        .map(|chunk| {
+            let mut itr = chunk.lines();
+
+            // get every 4th, 5th, and 15th instruction after an "inp"
+            let div = itr.nth(4);
+            let digit = [
+                parse_instruction(&src, div, chunk)?,
+                parse_instruction(&src, itr.next(), chunk)?,
+                parse_instruction(&src, itr.nth(9), chunk)?,
+            ];
+
+            // Each digit either pushes onto or pops off the stack kept in `z`
+            if digit[0] != 1 && digit[0] != 26 {
+                return Err(src.error(div.unwrap_or(chunk), "expected `div z 1` or `div z 26`"));
+            }
+
+            Ok(digit)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() != 14 {
+        return Err(src.error(input, "expected 14 digits"));
+    }
+
+    Ok(digits)
+}
+
+// `None` if no model number is valid: the pushes and pops don't pair up, or the digits of a pair
+// would have to differ by more than 8
+fn solve<F, const N: isize>(instructions: &[[isize; 3]], pick: F) -> Option<usize>
+where
+    F: Fn(isize, isize) -> isize,
+{
+    let mut stack = Vec::new();
+    let mut num = [0; 14];
+
+    for (i, &[div_x, add_x, add_y]) in instructions.iter().enumerate() {
+        if div_x == 1 {
+            stack.push((i, add_y));
+        } else {
+            let (j, ins_y) = stack.pop()?;
+            let delta = ins_y.checked_add(add_x)?;
+            if delta.abs() > 8 {
+                return None;
+            }
+
+            num[i] = pick(N, N + delta);
+            num[j] = pick(N, N - delta);
+        }
+    }
+
+    if !stack.is_empty() {
+        return None;
+    }
+
+    Some(num.iter().fold(0, |acc, &d| acc * 10 + d as usize))
+}
+
+#[aoc(day24, part1)]
+pub fn part1(instructions: &[[isize; 3]]) -> Option<usize> {
+    solve::<_, 9>(instructions, min)
+}
+
+#[aoc(day24, part2)]
+pub fn part2(instructions: &[[isize; 3]]) -> Option<usize> {
+    solve::<_, 1>(instructions, max)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 24;
+
+    type Parsed = Vec<[isize; 3]>;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Option<usize> {
+        part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Option<usize> {
+        part2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_input() {
+        // There is no sample program, so use a generated one
+        let input = crate::r#gen::find(crate::y2021::YEAR, 24)
+            .unwrap()
+            .generate(0, 14);
+        assert_eq!(generator(&input).unwrap().len(), 14);
+
+        // assert_eq!(generator(SAMPLE), Day24());
+    }
+
+    // Seven pairs of digits, the digits of each pair differing by `deltas[pair]`
+    fn program(deltas: [isize; 7]) -> Vec<[isize; 3]> {
+        (0..7)
+            .flat_map(|pair| [[1, 10, 0], [26, deltas[pair], 0]])
+            .collect()
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&program([0; 7])), Some(99_999_999_999_999));
+        assert_eq!(
+            part1(&program([8, -8, 1, 0, 0, 0, 0])),
+            Some(19_91_89_99_99_99_99)
+        );
+        assert_eq!(part1(&program([9, 0, 0, 0, 0, 0, 0])), None);
+    }
+
+    #[test]
+    pub fn test2() {
+        assert_eq!(part2(&program([0; 7])), Some(11_111_111_111_111));
+        assert_eq!(
+            part2(&program([8, -8, 1, 0, 0, 0, 0])),
+            Some(19_91_12_11_11_11_11)
+        );
+
+        // A digit popped before anything was pushed
+        let mut unbalanced = program([0; 7]);
+        unbalanced.swap(0, 1);
+        assert_eq!(part2(&unbalanced), None);
+    }
+
+    #[test]
+    pub fn test_invalid() {
+        let input = crate::r#gen::find(crate::y2021::YEAR, 24)
+            .unwrap()
+            .generate(0, 14);
+        let (first, _) = input.split_once("\ninp").unwrap();
+
+        assert_eq!(generator(first).unwrap_err().reason, "expected 14 digits");
+        assert!(generator(&input.replace("div z 26", "div z 2")).is_err());
+    }
+}
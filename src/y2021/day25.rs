@@ -0,0 +1,200 @@
+use std::{fmt, mem::swap};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::{
+    error::{ParseError, Source},
+    grid::{Grid, Pos},
+    solution::Solution,
+};
+
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SeaCucumber {
+    East,
+    South,
+    Empty,
+}
+
+impl SeaCucumber {
+    fn parse_byte(b: u8) -> Option<Self> {
+        match b {
+            b'.' => Some(Self::Empty),
+            b'>' => Some(Self::East),
+            b'v' => Some(Self::South),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SeaCucumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::East => write!(f, ">"),
+            Self::South => write!(f, "v"),
+            Self::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Map(Grid<SeaCucumber>);
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.0)
+    }
+}
+
+impl Map {
+    fn new_empty(&self) -> Self {
+        Self(Grid::new(
+            self.0.width(),
+            self.0.height(),
+            SeaCucumber::Empty,
+        ))
+    }
+
+    fn tick_east(&self, target: &mut Self) {
+        // assume target is clear;
+
+        for p in self.0.positions() {
+            match self.0[p] {
+                SeaCucumber::East => {
+                    let right = self.0.wrap(p, (0, 1));
+                    if self.is_empty(right) {
+                        target.0[right] = SeaCucumber::East;
+                    } else {
+                        target.0[p] = SeaCucumber::East;
+                    }
+                }
+                SeaCucumber::South => target.0[p] = SeaCucumber::South,
+                SeaCucumber::Empty => {}
+            }
+        }
+    }
+
+    fn tick_south(&self, target: &mut Self) {
+        for p in self.0.positions() {
+            match self.0[p] {
+                SeaCucumber::South => {
+                    let down = self.0.wrap(p, (1, 0));
+                    if self.is_empty(down) {
+                        target.0[down] = SeaCucumber::South;
+                    } else {
+                        target.0[p] = SeaCucumber::South;
+                    }
+                }
+                SeaCucumber::East => target.0[p] = SeaCucumber::East,
+                SeaCucumber::Empty => {}
+            }
+        }
+    }
+
+    fn is_empty(&self, p: Pos) -> bool {
+        self.0[p] == SeaCucumber::Empty
+    }
+
+    fn clear(&mut self) {
+        self.0.fill(SeaCucumber::Empty);
+    }
+}
+
+#[aoc_generator(day25)]
+pub fn generator(input: &str) -> Result<Map, ParseError> {
+    let src = Source::new(25, input);
+
+    Grid::parse(
+        &src,
+        input,
+        |c| u8::try_from(c).ok().and_then(SeaCucumber::parse_byte),
+        "expected `.`, `>` or `v`",
+    )
+    .map(Map)
+}
+
+#[aoc(day25, part1)]
+pub fn part1(inputs: &Map) -> Option<usize> {
+    let mut inputs = inputs.clone();
+    let mut target1 = inputs.new_empty();
+    let mut target2 = inputs.new_empty();
+
+    // A herd that can go round the sea floor forever comes back to an earlier state, checking
+    // against one saved every power of two steps finds it without keeping them all
+    let mut checkpoint = inputs.clone();
+    let mut x = 0_usize;
+    loop {
+        x += 1;
+        inputs.tick_east(&mut target1);
+
+        target1.tick_south(&mut target2);
+        if target2 == inputs {
+            break Some(x);
+        }
+
+        swap(&mut inputs, &mut target2);
+        target1.clear();
+        target2.clear();
+
+        if inputs == checkpoint {
+            break None;
+        }
+        if x.is_power_of_two() {
+            checkpoint.clone_from(&inputs);
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type Parsed = Map;
+    type Answer1 = Option<usize>;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generator(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Option<usize> {
+        part1(parsed)
+    }
+
+    fn part2(_parsed: &Self::Parsed) -> usize {
+        unreachable!("day 25 only has one part")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r"v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    pub fn test_input() {
+        println!("{:?}", generator(SAMPLE).unwrap());
+
+        // assert_eq!(generator(SAMPLE), Object());
+    }
+
+    #[test]
+    pub fn test1() {
+        assert_eq!(part1(&generator(SAMPLE).unwrap()), Some(58));
+        assert_eq!(part1(&generator(">.").unwrap()), None);
+        assert_eq!(part1(&generator(">.v\n...\n...").unwrap()), None);
+    }
+}
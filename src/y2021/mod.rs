@@ -0,0 +1,67 @@
+//! Advent of Code 2021, the year `cargo aoc` runs through the `aoc_lib!` in `lib.rs`.
+
+use crate::solution::{Entry, entries};
+
+pub const YEAR: u16 = 2021;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+// Insert before
+
+/// The year's entries, ordered by day.
+pub fn registry() -> Vec<Entry> {
+    [
+        entries::<day01::Solver>(),
+        entries::<day02::Solver>(),
+        entries::<day03::Solver>(),
+        entries::<day04::Solver>(),
+        entries::<day05::Solver>(),
+        entries::<day06::Solver>(),
+        entries::<day07::Solver>(),
+        entries::<day08::Solver>(),
+        entries::<day09::Solver>(),
+        entries::<day10::Solver>(),
+        entries::<day11::Solver>(),
+        entries::<day12::Solver>(),
+        entries::<day13::Solver>(),
+        entries::<day14::Solver>(),
+        entries::<day15::Solver>(),
+        entries::<day16::Solver>(),
+        entries::<day17::Solver>(),
+        entries::<day18::Solver>(),
+        entries::<day19::Solver>(),
+        entries::<day20::Solver>(),
+        entries::<day21::Solver>(),
+        entries::<day22::Solver>(),
+        entries::<day23::Solver>(),
+        entries::<day24::Solver>(),
+        entries::<day25::Solver>(),
+        // Insert before
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}